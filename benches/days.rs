@@ -0,0 +1,31 @@
+//! `cargo bench` entry point, running `aoc bench` built with the bench profile.
+//!
+//! Arguments after `--` are forwarded, e.g. `cargo bench -- 5 --save-baseline`.
+
+use std::env;
+use std::process::{Command, ExitCode};
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+
+    // Cargo adds `--bench` to the arguments of every benchmark target under `cargo bench`, and
+    // leaves it out when `cargo test --all-targets` builds them as tests, which then has nothing
+    // to check.
+    if !args.iter().any(|arg| arg == "--bench") {
+        return ExitCode::SUCCESS;
+    }
+    args.retain(|arg| arg != "--bench");
+
+    match Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("bench")
+        .args(args)
+        .status()
+    {
+        Ok(status) if status.success() => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("Couldn't run aoc: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
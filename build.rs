@@ -0,0 +1,29 @@
+//! Links the crate of every day the runner depends on, so that their solutions register
+//! themselves without a hand-maintained list of days.
+
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use toml_edit::DocumentMut;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    println!("cargo::rerun-if-changed=Cargo.toml");
+
+    let manifest = fs::read_to_string("Cargo.toml")?.parse::<DocumentMut>()?;
+    let links = manifest["dependencies"]
+        .as_table()
+        .ok_or("Cargo.toml has no dependencies")?
+        .iter()
+        .map(|(name, _)| name)
+        .filter(|name| {
+            name.strip_prefix("day-")
+                .is_some_and(|day| day.parse::<u8>().is_ok())
+        })
+        .map(|name| format!("use {} as _;\n", name.replace('-', "_")))
+        .collect::<String>();
+
+    fs::write(PathBuf::from(env::var("OUT_DIR")?).join("days.rs"), links)?;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use aoc_macros::aoc_generator;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use solution::generator::GeneratorRng;
+
+/// Generates two lists of `lines` location IDs, the right one repeating some IDs of the left one
+/// so that they have a similarity score.
+#[aoc_generator(day = 1)]
+pub fn generate(rng: &mut GeneratorRng, lines: usize) -> String {
+    let left = (0..lines)
+        .map(|_| rng.gen_range(10_000..100_000))
+        .collect::<Vec<u32>>();
+    let right = (0..lines)
+        .map(|_| {
+            if rng.gen_bool(0.5) {
+                *left.choose(rng).expect("There is at least one line")
+            } else {
+                rng.gen_range(10_000..100_000)
+            }
+        })
+        .collect::<Vec<u32>>();
+
+    left.iter()
+        .zip(&right)
+        .map(|(left, right)| format!("{left}   {right}\n"))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day1;
+
+    solution::generator_tests!(Day1, generate);
+}
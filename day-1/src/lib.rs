@@ -0,0 +1,80 @@
+mod generator;
+#[cfg(test)]
+mod reference;
+
+use file_reader::file_reader::FileReader;
+use file_reader::parse::ParseError;
+use solution::Solution;
+use std::collections::HashMap;
+use std::error::Error;
+
+type ParsingData = (Vec<i32>, Vec<i32>, HashMap<i32, i32>);
+
+pub struct Day1;
+
+solution::register!(Day1, day = 1);
+
+impl Solution for Day1 {
+    type Input = ParsingData;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        let (mut left_vec, mut right_vec, frequency_map) = parse(reader)?;
+
+        left_vec.sort_unstable();
+        right_vec.sort_unstable();
+
+        Ok((left_vec, right_vec, frequency_map))
+    }
+
+    fn part_one((left_list, right_list, _): &Self::Input) -> Self::PartOne {
+        left_list
+            .iter()
+            .zip(right_list.iter())
+            .map(|(left_number, right_number)| (left_number - right_number).abs())
+            .sum()
+    }
+
+    fn part_two((left_list, _, frequency_map): &Self::Input) -> Self::PartTwo {
+        left_list
+            .iter()
+            .map(|left_number| left_number * *frequency_map.get(left_number).get_or_insert(&0))
+            .sum()
+    }
+}
+
+fn parse(reader: FileReader) -> Result<ParsingData, ParseError> {
+    let mut left_vec = Vec::new();
+    let mut right_vec = Vec::new();
+    let mut frequency_map = HashMap::new();
+
+    let pairs = reader.parse_lines_with(|line| {
+        let mut split = line.text().split_whitespace();
+
+        let left_number = split.next().ok_or_else(|| line.missing("left number"))?;
+        let right_number = split.next().ok_or_else(|| line.missing("right number"))?;
+
+        Ok((
+            line.parse::<i32>(left_number)?,
+            line.parse::<i32>(right_number)?,
+        ))
+    })?;
+
+    for (left_number, right_number) in pairs {
+        left_vec.push(left_number);
+        right_vec.push(right_number);
+
+        let frequency_count = frequency_map.entry(right_number).or_insert(0);
+        *frequency_count += 1;
+    }
+
+    Ok((left_vec, right_vec, frequency_map))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    solution::examples!(Day1);
+}
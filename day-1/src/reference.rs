@@ -0,0 +1,65 @@
+//! A slow but obviously correct solution, which the solution of the day is tested against.
+
+use crate::generator::generate;
+use crate::Day1;
+use file_reader::file_reader::FileReader;
+use solution::Solution;
+use std::error::Error;
+
+pub struct Day1Reference;
+
+impl Solution for Day1Reference {
+    type Input = (Vec<i64>, Vec<i64>);
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        let mut lists = (Vec::new(), Vec::new());
+
+        for line in reader {
+            let numbers = line
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<Vec<_>, _>>()?;
+            let [left, right] = numbers[..] else {
+                return Err(format!("Expected two location IDs in `{}`", line.trim()).into());
+            };
+
+            lists.0.push(left);
+            lists.1.push(right);
+        }
+
+        Ok(lists)
+    }
+
+    /// Pairs the smallest remaining IDs of both lists, one pair at a time.
+    fn part_one((left, right): &Self::Input) -> Self::PartOne {
+        let (mut left, mut right) = (left.clone(), right.clone());
+        let mut distance = 0;
+
+        while let (Some(smallest_left), Some(smallest_right)) =
+            (take_min(&mut left), take_min(&mut right))
+        {
+            distance += (smallest_left - smallest_right).abs();
+        }
+
+        distance
+    }
+
+    fn part_two((left, right): &Self::Input) -> Self::PartTwo {
+        left.iter()
+            .map(|id| {
+                let occurrences = right.iter().filter(|other| *other == id).count();
+                id * i64::try_from(occurrences).expect("A list has less than i64::MAX IDs")
+            })
+            .sum()
+    }
+}
+
+fn take_min(list: &mut Vec<i64>) -> Option<i64> {
+    let index = (0..list.len()).min_by_key(|index| list[*index])?;
+
+    Some(list.swap_remove(index))
+}
+
+solution::differential_tests!(Day1, Day1Reference, generate);
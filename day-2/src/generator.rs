@@ -0,0 +1,47 @@
+use aoc_macros::aoc_generator;
+use rand::Rng;
+use solution::generator::GeneratorRng;
+
+/// Generates `reports` reports of 5 to 8 levels, steadily increasing or decreasing by 1 to 3,
+/// about half of which have one level set off by some random value.
+#[aoc_generator(day = 2)]
+pub fn generate(rng: &mut GeneratorRng, reports: usize) -> String {
+    (0..reports)
+        .map(|_| {
+            let length = rng.gen_range(5..=8);
+            let increasing = rng.gen_bool(0.5);
+            let mut level = if increasing {
+                rng.gen_range(1..=70)
+            } else {
+                rng.gen_range(30..=99)
+            };
+            let mut levels = vec![level];
+
+            for _ in 1..length {
+                let step = rng.gen_range(1..=3);
+                level = if increasing {
+                    level + step
+                } else {
+                    level - step
+                };
+                levels.push(level);
+            }
+
+            if rng.gen_bool(0.5) {
+                let index = rng.gen_range(0..length);
+                levels[index] = rng.gen_range(1..=99);
+            }
+
+            let levels = levels.iter().map(u32::to_string).collect::<Vec<_>>();
+            levels.join(" ") + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day2;
+
+    solution::generator_tests!(Day2, generate);
+}
@@ -0,0 +1,64 @@
+mod generator;
+#[cfg(test)]
+mod reference;
+pub mod report;
+
+use file_reader::file_reader::FileReader;
+use report::Report;
+use solution::Solution;
+use std::error::Error;
+
+pub struct Day2;
+
+solution::register!(Day2, day = 2);
+
+impl Solution for Day2 {
+    type Input = Vec<Report>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        let reports = reader.parse_lines_with(|line| {
+            let numbers = line
+                .text()
+                .split_whitespace()
+                .map(|number| line.parse::<usize>(number))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Report::new(numbers))
+        })?;
+
+        Ok(reports)
+    }
+
+    fn part_one(reports: &Self::Input) -> Self::PartOne {
+        reports
+            .iter()
+            .map(|report| report.get_safetyness().get_value())
+            .sum()
+    }
+
+    fn part_two(reports: &Self::Input) -> Self::PartTwo {
+        reports
+            .iter()
+            .map(|report| report.get_safetyness_with_tolerance().get_value())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::report::Safetyness::Safe;
+
+    solution::examples!(Day2);
+
+    #[test]
+    fn test_safetyness() {
+        let report = Report::new(vec![8, 6, 4, 4, 1]);
+
+        let safetyness = report.get_safetyness_with_tolerance();
+
+        assert!(matches!(safetyness, Safe));
+    }
+}
@@ -0,0 +1,62 @@
+//! A slow but obviously correct solution, which the solution of the day is tested against.
+
+use crate::Day2;
+use crate::generator::generate;
+use file_reader::file_reader::FileReader;
+use solution::Solution;
+use std::error::Error;
+
+pub struct Day2Reference;
+
+impl Solution for Day2Reference {
+    type Input = Vec<Vec<i64>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        reader
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                Ok(line
+                    .split_whitespace()
+                    .map(str::parse)
+                    .collect::<Result<_, _>>()?)
+            })
+            .collect()
+    }
+
+    fn part_one(reports: &Self::Input) -> Self::PartOne {
+        reports.iter().filter(|report| is_safe(report)).count()
+    }
+
+    /// Tries every report as is, then without each one of its levels.
+    fn part_two(reports: &Self::Input) -> Self::PartTwo {
+        reports
+            .iter()
+            .filter(|report| {
+                is_safe(report)
+                    || (0..report.len()).any(|removed| {
+                        let mut report = (*report).clone();
+                        report.remove(removed);
+                        is_safe(&report)
+                    })
+            })
+            .count()
+    }
+}
+
+fn is_safe(report: &[i64]) -> bool {
+    let differences = report
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .collect::<Vec<_>>();
+
+    differences
+        .iter()
+        .all(|difference| (1..=3).contains(difference))
+        || differences
+            .iter()
+            .all(|difference| (-3..=-1).contains(difference))
+}
+
+solution::differential_tests!(Day2, Day2Reference, generate);
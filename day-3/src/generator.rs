@@ -0,0 +1,83 @@
+use aoc_macros::aoc_generator;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use solution::generator::GeneratorRng;
+
+/// Characters the memory is corrupted with.
+const JUNK_CHARACTERS: &[char] = &[
+    '!', '@', '#', '$', '%', '^', '&', '*', '(', ')', '[', ']', '{', '}', '<', '>', '+', '-', '/',
+    ',', ';', ':', '\'', '~', '?', ' ',
+];
+/// Corrupted instructions, which must not be mistaken for valid ones.
+const JUNK_INSTRUCTIONS: &[&str] = &[
+    "mul(4*",
+    "mul[3,7]",
+    "mul(32,64]",
+    "mul(6,9!",
+    "?(12,34)",
+    "mul ",
+    "don't",
+    "do(",
+    "what()",
+    "who()",
+    "select()",
+    "from()",
+    "how()",
+    "where()",
+    "why()",
+    "when()",
+];
+
+/// Generates corrupted memory holding `instructions` valid `mul` instructions, with `do()` and
+/// `don't()` instructions and junk in between, split over a few lines.
+#[aoc_generator(day = 3)]
+pub fn generate(rng: &mut GeneratorRng, instructions: usize) -> String {
+    let mut memory = String::new();
+
+    for index in 0..instructions {
+        for _ in 0..rng.gen_range(0..=6) {
+            if rng.gen_bool(0.2) {
+                memory.push_str(JUNK_INSTRUCTIONS.choose(rng).expect("There is some junk"));
+            } else {
+                memory.push(*JUNK_CHARACTERS.choose(rng).expect("There is some junk"));
+            }
+        }
+
+        match rng.gen_range(0..10) {
+            0 => memory.push_str("do()"),
+            1 => memory.push_str("don't()"),
+            _ => {}
+        }
+
+        memory.push_str(&format!(
+            "mul({},{})",
+            rng.gen_range(1..1000),
+            rng.gen_range(1..1000)
+        ));
+
+        if index % 100 == 99 {
+            memory.push('\n');
+        }
+    }
+
+    memory + "\n"
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day3;
+
+    solution::generator_tests!(Day3, generate);
+
+    #[test]
+    fn test_every_instruction_is_parsed() -> Result<(), Box<dyn std::error::Error>> {
+        for seed in 0..solution::generator::TESTED_SEEDS {
+            let memory = generate(&mut solution::generator::rng(seed), 50);
+
+            assert_eq!(50, crate::entities::parser::parse_stdin(&memory)?.len());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::entities::mul_expression::MulExpression;
+use crate::entities::parser::parse_stdin;
+use file_reader::file_reader::FileReader;
+use solution::Solution;
+use std::error::Error;
+
+mod entities;
+mod generator;
+#[cfg(test)]
+mod reference;
+
+pub struct Day3;
+
+solution::register!(Day3, day = 3);
+
+impl Solution for Day3 {
+    type Input = Vec<MulExpression>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        let file = reader.read_to_string()?;
+
+        Ok(parse_stdin(&file)?)
+    }
+
+    fn part_one(mul_expressions: &Self::Input) -> Self::PartOne {
+        mul_expressions.iter().map(MulExpression::evaluate).sum()
+    }
+
+    fn part_two(mul_expressions: &Self::Input) -> Self::PartTwo {
+        mul_expressions
+            .iter()
+            .map(MulExpression::evaluate_by_instruction)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    solution::examples!(Day3);
+}
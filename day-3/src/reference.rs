@@ -0,0 +1,66 @@
+//! A slow but obviously correct solution, which the solution of the day is tested against.
+
+use crate::Day3;
+use crate::generator::generate;
+use file_reader::file_reader::FileReader;
+use solution::Solution;
+use std::error::Error;
+
+pub struct Day3Reference;
+
+impl Solution for Day3Reference {
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(reader.read_to_string()?)
+    }
+
+    fn part_one(memory: &Self::Input) -> Self::PartOne {
+        evaluate(memory, false)
+    }
+
+    fn part_two(memory: &Self::Input) -> Self::PartTwo {
+        evaluate(memory, true)
+    }
+}
+
+/// Reads the memory one position at a time, trying every instruction there.
+fn evaluate(memory: &str, follow_instructions: bool) -> usize {
+    let mut enabled = true;
+    let mut sum = 0;
+
+    for (start, _) in memory.char_indices() {
+        let rest = &memory[start..];
+
+        if rest.starts_with("do()") {
+            enabled = true;
+        } else if rest.starts_with("don't()") {
+            enabled = !follow_instructions;
+        } else if let Some((left, right)) = read_mul(rest)
+            && enabled
+        {
+            sum += left * right;
+        }
+    }
+
+    sum
+}
+
+/// Reads a `mul(<number>,<number>)` instruction at the start of `text`.
+fn read_mul(text: &str) -> Option<(usize, usize)> {
+    let arguments = text.strip_prefix("mul(")?;
+    let (left, arguments) = read_number(arguments)?;
+    let arguments = arguments.strip_prefix(',')?;
+    let (right, arguments) = read_number(arguments)?;
+    arguments.starts_with(')').then_some((left, right))
+}
+
+fn read_number(text: &str) -> Option<(usize, &str)> {
+    let digits = text.chars().take_while(char::is_ascii_digit).count();
+
+    Some((text[..digits].parse().ok()?, &text[digits..]))
+}
+
+solution::differential_tests!(Day3, Day3Reference, generate);
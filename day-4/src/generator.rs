@@ -0,0 +1,25 @@
+use aoc_macros::aoc_generator;
+use rand::seq::SliceRandom;
+use solution::generator::GeneratorRng;
+
+/// Generates a square grid of `size` by `size` letters of `XMAS`.
+#[aoc_generator(day = 4)]
+pub fn generate(rng: &mut GeneratorRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut line = (0..size)
+                .map(|_| *['X', 'M', 'A', 'S'].choose(rng).expect("There are letters"))
+                .collect::<String>();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day4;
+
+    solution::generator_tests!(Day4, generate);
+}
@@ -0,0 +1,204 @@
+#![warn(clippy::pedantic)]
+mod generator;
+#[cfg(test)]
+mod reference;
+pub mod xmas_symbol;
+
+use DirectionMove::{DownLeft, DownRight, UpLeft, UpRight};
+use array_utils::coordinate::Coordinates;
+use array_utils::grid::Grid;
+use array_utils::{DirectionMove, TableUtils};
+use file_reader::file_reader::FileReader;
+use file_reader::parse::ParseError;
+use macro_utils::destruct_options;
+use solution::Solution;
+use std::error::Error;
+use std::str::Chars;
+use xmas_symbol::XmasSymbol;
+
+type XmasTable = Grid<XmasSymbol>;
+
+pub struct Day4;
+
+solution::register!(Day4, day = 4);
+
+impl Solution for Day4 {
+    type Input = (XmasTable, usize);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_file(reader)?)
+    }
+
+    fn part_one((symbol_table, horizontal_xmas_count): &Self::Input) -> Self::PartOne {
+        let xmas_diagonal_count = symbol_table
+            .iter_table_with_coordinates()
+            .filter(|(symbol, ..)| **symbol == XmasSymbol::X)
+            .map(|(_, coordinates)| {
+                count_word_in_any_diagonal(symbol_table, &"MAS".chars(), coordinates.to_tuple())
+            })
+            .sum::<usize>();
+
+        horizontal_xmas_count + xmas_diagonal_count
+    }
+
+    fn part_two((symbol_table, _): &Self::Input) -> Self::PartTwo {
+        symbol_table
+            .iter_table_with_coordinates()
+            .filter(|(symbol, ..)| **symbol == XmasSymbol::A)
+            .map(|(_, coordinates)| {
+                let (up_left, up_right, down_left, down_right) =
+                    get_adjacent_symbols(symbol_table, coordinates);
+
+                let (up_left, up_right, down_left, down_right) =
+                    destruct_options!(up_left, up_right, down_left, down_right ? return 0);
+                let (up_left, up_right, down_left, down_right) = (
+                    (up_left, UpLeft),
+                    (up_right, UpRight),
+                    (down_left, DownLeft),
+                    (down_right, DownRight),
+                );
+
+                let symbols_iter = [up_left, up_right, down_left, down_right].into_iter();
+
+                if symbols_iter
+                    .clone()
+                    .filter(|symbol| *symbol.0 == XmasSymbol::S)
+                    .count()
+                    == 2
+                    && symbols_iter
+                        .clone()
+                        .filter(|symbol| *symbol.0 == XmasSymbol::M)
+                        .count()
+                        == 2
+                {
+                    let var = symbols_iter
+                        .filter(|symbol| *symbol.0 == XmasSymbol::M)
+                        .map(|(_, direction)| direction)
+                        .collect::<Vec<_>>();
+
+                    let (m1_direction, m2_direction) = (var[0], var[1]);
+
+                    if !m1_direction.is_opposed_to(m2_direction) {
+                        return 1;
+                    }
+                }
+
+                0
+            })
+            .sum::<usize>()
+    }
+}
+
+fn get_adjacent_symbols(
+    symbol_table: &XmasTable,
+    coordinates: Coordinates,
+) -> (
+    Option<&XmasSymbol>,
+    Option<&XmasSymbol>,
+    Option<&XmasSymbol>,
+    Option<&XmasSymbol>,
+) {
+    let up_left = symbol_table.get_from_coordinate_move(coordinates, UpLeft);
+    let up_right = symbol_table.get_from_coordinate_move(coordinates, UpRight);
+    let down_left = symbol_table.get_from_coordinate_move(coordinates, DownLeft);
+    let down_right = symbol_table.get_from_coordinate_move(coordinates, DownRight);
+    (up_left, up_right, down_left, down_right)
+}
+
+fn count_word_in_any_diagonal(
+    symbol_table: &XmasTable,
+    word: &Chars,
+    base_coordinate: (usize, usize),
+) -> usize {
+    let mut count = 0;
+
+    if match_word_in_direction(symbol_table, word.clone(), base_coordinate, DownRight) {
+        count += 1;
+    }
+
+    if match_word_in_direction(symbol_table, word.clone(), base_coordinate, DownLeft) {
+        count += 1;
+    }
+
+    if match_word_in_direction(symbol_table, word.clone(), base_coordinate, UpRight) {
+        count += 1;
+    }
+
+    if match_word_in_direction(symbol_table, word.clone(), base_coordinate, UpLeft) {
+        count += 1;
+    }
+
+    if match_word_in_direction(
+        symbol_table,
+        word.clone(),
+        base_coordinate,
+        DirectionMove::Down,
+    ) {
+        count += 1;
+    }
+
+    if match_word_in_direction(
+        symbol_table,
+        word.clone(),
+        base_coordinate,
+        DirectionMove::Up,
+    ) {
+        count += 1;
+    }
+
+    count
+}
+
+fn match_word_in_direction(
+    symbol_table: &XmasTable,
+    mut word: Chars,
+    base_coordinate: (usize, usize),
+    direction: DirectionMove,
+) -> bool {
+    let (move_x, move_y) = direction.get_direction();
+    let symbol_to_match = match word.next() {
+        Some(char) => XmasSymbol::try_from(char).expect("The word is made of `XMAS` letters"),
+        None => return true, // If it get to this point, all chars of the word have been checked
+    };
+
+    let x_destination = base_coordinate.0.checked_add_signed(move_x);
+    let y_destination = base_coordinate.1.checked_add_signed(move_y);
+
+    let (x_destination, y_destination) =
+        destruct_options!(x_destination, y_destination ? return false);
+
+    if symbol_table.get_from_coordinates(x_destination, y_destination) != Some(&symbol_to_match) {
+        return false;
+    }
+
+    match_word_in_direction(
+        symbol_table,
+        word,
+        (x_destination, y_destination),
+        direction,
+    )
+}
+
+fn parse_file(reader: FileReader) -> Result<(XmasTable, usize), ParseError> {
+    let symbol_table = reader.read_grid::<XmasSymbol>()?;
+
+    let xmas_count = symbol_table
+        .iter()
+        .flat_map(|row| row.windows(4))
+        .filter(|word| {
+            *word == [XmasSymbol::X, XmasSymbol::M, XmasSymbol::A, XmasSymbol::S]
+                || *word == [XmasSymbol::S, XmasSymbol::A, XmasSymbol::M, XmasSymbol::X]
+        })
+        .count();
+
+    Ok((symbol_table, xmas_count))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    solution::examples!(Day4);
+}
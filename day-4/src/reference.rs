@@ -0,0 +1,89 @@
+//! A slow but obviously correct solution, which the solution of the day is tested against.
+
+use crate::Day4;
+use crate::generator::generate;
+use file_reader::file_reader::FileReader;
+use solution::Solution;
+use std::error::Error;
+
+/// Every direction a word can be read in, as steps along x and y.
+const DIRECTIONS: [(isize, isize); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+pub struct Day4Reference;
+
+impl Solution for Day4Reference {
+    type Input = Vec<Vec<char>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(reader
+            .map(|line| line.trim().chars().collect::<Vec<_>>())
+            .filter(|row| !row.is_empty())
+            .collect())
+    }
+
+    /// Reads a word from every tile in every direction.
+    fn part_one(grid: &Self::Input) -> Self::PartOne {
+        tiles(grid)
+            .map(|(x, y)| {
+                DIRECTIONS
+                    .iter()
+                    .filter(|(step_x, step_y)| {
+                        (0..4).all(|index| {
+                            letter(grid, x + step_x * index, y + step_y * index)
+                                == "XMAS".chars().nth(index.unsigned_abs())
+                        })
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Reads both diagonals crossing every tile.
+    fn part_two(grid: &Self::Input) -> Self::PartTwo {
+        tiles(grid)
+            .filter(|(x, y)| {
+                let diagonal = |from: (isize, isize), to: (isize, isize)| {
+                    let word = [
+                        letter(grid, x + from.0, y + from.1),
+                        letter(grid, *x, *y),
+                        letter(grid, x + to.0, y + to.1),
+                    ];
+                    word == [Some('M'), Some('A'), Some('S')]
+                        || word == [Some('S'), Some('A'), Some('M')]
+                };
+
+                diagonal((-1, -1), (1, 1)) && diagonal((1, -1), (-1, 1))
+            })
+            .count()
+    }
+}
+
+fn tiles(grid: &[Vec<char>]) -> impl Iterator<Item = (isize, isize)> + '_ {
+    grid.iter().enumerate().flat_map(|(y, row)| {
+        (0..row.len()).map(move |x| {
+            (
+                isize::try_from(x).expect("The grid is narrower than isize::MAX"),
+                isize::try_from(y).expect("The grid is shorter than isize::MAX"),
+            )
+        })
+    })
+}
+
+fn letter(grid: &[Vec<char>], x: isize, y: isize) -> Option<char> {
+    let row = grid.get(usize::try_from(y).ok()?)?;
+
+    row.get(usize::try_from(x).ok()?).copied()
+}
+
+solution::differential_tests!(Day4, Day4Reference, generate);
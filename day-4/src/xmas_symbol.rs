@@ -1,3 +1,6 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
 #[derive(Debug, PartialEq)]
 pub enum XmasSymbol {
     X,
@@ -7,14 +10,27 @@ pub enum XmasSymbol {
     Point,
 }
 
-impl From<char> for XmasSymbol {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for XmasSymbol {
+    type Error = InvalidSymbol;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            'X' => XmasSymbol::X,
-            'M' => XmasSymbol::M,
-            'A' => XmasSymbol::A,
-            'S' => XmasSymbol::S,
-            _ => panic!("Invalid character: {c}"),
+            'X' => Ok(XmasSymbol::X),
+            'M' => Ok(XmasSymbol::M),
+            'A' => Ok(XmasSymbol::A),
+            'S' => Ok(XmasSymbol::S),
+            _ => Err(InvalidSymbol(c)),
         }
     }
 }
+
+#[derive(Debug)]
+pub struct InvalidSymbol(char);
+
+impl Display for InvalidSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Expected one of `XMAS`, got `{}`", self.0)
+    }
+}
+
+impl Error for InvalidSymbol {}
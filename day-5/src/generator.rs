@@ -0,0 +1,47 @@
+use aoc_macros::aoc_generator;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use solution::generator::GeneratorRng;
+
+/// Generates `updates` updates of an odd number of pages, about half of them in the right
+/// order.
+///
+/// The rules order every pair of pages, following one random order of all of them, so that
+/// every update can be put in order.
+#[aoc_generator(day = 5)]
+pub fn generate(rng: &mut GeneratorRng, updates: usize) -> String {
+    let mut pages = (10..100).collect::<Vec<usize>>();
+    pages.shuffle(rng);
+    pages.truncate(updates.clamp(5, pages.len()));
+
+    let mut rules = Vec::new();
+    for (index, before) in pages.iter().enumerate() {
+        for after in &pages[index + 1..] {
+            rules.push(format!("{before}|{after}\n"));
+        }
+    }
+    rules.shuffle(rng);
+
+    let longest_update = (pages.len() - 1) / 2;
+    let updates = (0..updates).map(|_| {
+        let length = 2 * rng.gen_range(1..=longest_update.min(11)) + 1;
+        let mut update = pages.choose_multiple(rng, length).collect::<Vec<_>>();
+
+        if rng.gen_bool(0.5) {
+            update.sort_by_key(|page| pages.iter().position(|other| other == *page));
+        }
+
+        let update = update.iter().map(ToString::to_string).collect::<Vec<_>>();
+        update.join(",") + "\n"
+    });
+
+    rules.concat() + "\n" + &updates.collect::<String>()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day5;
+
+    solution::generator_tests!(Day5, generate);
+}
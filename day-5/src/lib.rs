@@ -0,0 +1,161 @@
+#![warn(clippy::pedantic)]
+
+mod generator;
+mod page_rule;
+#[cfg(test)]
+mod reference;
+
+use crate::page_rule::PageRule;
+use file_reader::file_reader::FileReader;
+use file_reader::parse::{FieldError, Line};
+use solution::Solution;
+use std::collections::HashSet;
+use std::error::Error;
+
+pub struct Day5;
+
+solution::register!(Day5, day = 5);
+
+impl Solution for Day5 {
+    type Input = (HashSet<PageRule>, Vec<Vec<usize>>);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    #[allow(clippy::mutable_key_type)]
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        let sections = reader.sections()?;
+        let [rules_section, updates_section] = &sections[..] else {
+            return Err(format!(
+                "Expected the page ordering rules then the updates, separated by a blank line, got {} sections",
+                sections.len()
+            )
+            .into());
+        };
+
+        let page_rules = rules_section.reader().parse_lines_with(parse_rule)?;
+        let updates = updates_section.reader().parse_lines_with(parse_update)?;
+
+        let page_rules_set = page_rules.iter().fold(
+            HashSet::new(),
+            |mut set: HashSet<PageRule>, (left_num, right_num)| {
+                append_number_to_page(&mut set, *left_num, *right_num);
+
+                set
+            },
+        );
+
+        Ok((page_rules_set, updates))
+    }
+
+    fn part_one((page_rules_set, updates): &Self::Input) -> Self::PartOne {
+        updates
+            .iter()
+            .filter(|update| is_update_valid(page_rules_set, update))
+            .map(|update| get_middle_number_from_vec(update))
+            .sum()
+    }
+
+    fn part_two((page_rules_set, updates): &Self::Input) -> Self::PartTwo {
+        let mut invalid_updates_page_rules = updates
+            .iter()
+            .filter(|update| !is_update_valid(page_rules_set, update))
+            .map(|update| {
+                update
+                    .iter()
+                    .filter_map(|number| page_rules_set.get(number))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        invalid_updates_page_rules
+            .iter_mut()
+            .map(|update| {
+                let update_len = update.len();
+                let mut corrected_update = Vec::new();
+
+                while corrected_update.len() != update_len {
+                    let numbers_to_filter =
+                        update
+                            .iter()
+                            .fold(HashSet::new(), |mut set: HashSet<usize>, page| {
+                                set.extend(page.get_ref_pages_number_after().iter());
+                                set
+                            });
+
+                    let number_to_add = update
+                        .iter()
+                        .map(|page_rule| page_rule.get_number())
+                        .find(|number| !numbers_to_filter.contains(number))
+                        .unwrap_or_else(|| panic!("Couldn't find number for update: {update:?}"));
+
+                    corrected_update.push(number_to_add);
+                    update.retain(|page| page.get_number() != number_to_add);
+                }
+
+                get_middle_number_from_vec(&corrected_update)
+            })
+            .sum::<usize>()
+    }
+}
+
+#[allow(clippy::mutable_key_type)]
+fn is_update_valid(page_rules_set: &HashSet<PageRule>, update: &[usize]) -> bool {
+    let mut pages_not_to_be_present: HashSet<usize> = HashSet::new();
+
+    let mut is_update_valid = true;
+
+    update.iter().rev().for_each(|page_number| {
+        if let Some(page_number) = page_rules_set.get(page_number) {
+            pages_not_to_be_present.extend(page_number.get_ref_pages_number_after().iter());
+        }
+
+        if pages_not_to_be_present.contains(page_number) {
+            is_update_valid = false;
+        }
+    });
+
+    is_update_valid
+}
+
+#[allow(clippy::mutable_key_type)]
+fn append_number_to_page(set: &mut HashSet<PageRule>, left_num: usize, right_num: usize) {
+    if let Some(left_page) = set.get(&left_num) {
+        left_page.add_page_number_after(right_num);
+    } else {
+        let new_page = PageRule::new(left_num);
+        new_page.add_page_number_after(right_num);
+        set.insert(new_page);
+    }
+}
+
+fn get_middle_number_from_vec(numbers: &[usize]) -> usize {
+    *numbers
+        .get(numbers.len() / 2)
+        .expect("Middle element should exist")
+}
+
+fn parse_rule(line: Line) -> Result<(usize, usize), FieldError> {
+    let (left_number, right_number) = line
+        .text()
+        .split_once('|')
+        .ok_or_else(|| line.missing("`|` between the page numbers"))?;
+
+    Ok((
+        line.parse(left_number.trim())?,
+        line.parse(right_number.trim())?,
+    ))
+}
+
+fn parse_update(line: Line) -> Result<Vec<usize>, FieldError> {
+    line.text()
+        .split(',')
+        .map(|number| line.parse(number.trim()))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    solution::examples!(Day5);
+}
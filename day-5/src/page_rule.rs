@@ -21,8 +21,8 @@ impl PageRule {
         }
     }
 
-    pub fn add_page_number_after(&self, page: &usize) {
-        self.pages_after.borrow_mut().insert(*page);
+    pub fn add_page_number_after(&self, page: usize) {
+        self.pages_after.borrow_mut().insert(page);
     }
 
     pub fn get_number(&self) -> usize {
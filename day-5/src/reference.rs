@@ -0,0 +1,66 @@
+//! A slow but obviously correct solution, which the solution of the day is tested against.
+
+use crate::Day5;
+use crate::generator::generate;
+use file_reader::file_reader::FileReader;
+use solution::Solution;
+use std::error::Error;
+
+pub struct Day5Reference;
+
+impl Solution for Day5Reference {
+    type Input = (Vec<(usize, usize)>, Vec<Vec<usize>>);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        let mut rules = Vec::new();
+        let mut updates = Vec::new();
+
+        for line in reader {
+            let line = line.trim();
+
+            if let Some((before, after)) = line.split_once('|') {
+                rules.push((before.parse()?, after.parse()?));
+            } else if !line.is_empty() {
+                updates.push(line.split(',').map(str::parse).collect::<Result<_, _>>()?);
+            }
+        }
+
+        Ok((rules, updates))
+    }
+
+    fn part_one((rules, updates): &Self::Input) -> Self::PartOne {
+        updates
+            .iter()
+            .filter(|update| misplaced_pair(rules, update).is_none())
+            .map(|update| update[update.len() / 2])
+            .sum()
+    }
+
+    /// Puts the updates in order by swapping pages breaking a rule until none does.
+    fn part_two((rules, updates): &Self::Input) -> Self::PartTwo {
+        updates
+            .iter()
+            .filter(|update| misplaced_pair(rules, update).is_some())
+            .map(|update| {
+                let mut update = update.clone();
+
+                while let Some((first, second)) = misplaced_pair(rules, &update) {
+                    update.swap(first, second);
+                }
+
+                update[update.len() / 2]
+            })
+            .sum()
+    }
+}
+
+/// The positions of two pages of `update` printed in the opposite order of a rule.
+fn misplaced_pair(rules: &[(usize, usize)], update: &[usize]) -> Option<(usize, usize)> {
+    (0..update.len())
+        .flat_map(|first| (first + 1..update.len()).map(move |second| (first, second)))
+        .find(|(first, second)| rules.contains(&(update[*second], update[*first])))
+}
+
+solution::differential_tests!(Day5, Day5Reference, generate);
@@ -0,0 +1,89 @@
+use aoc_macros::aoc_generator;
+use rand::Rng;
+use solution::generator::GeneratorRng;
+use std::collections::HashSet;
+
+/// Share of the tiles of the map which are obstructed.
+const OBSTRUCTION_DENSITY: f64 = 0.1;
+
+/// Generates a square map of `size` by `size` tiles with scattered obstructions and a guard
+/// facing up, whose patrol leaves the map.
+///
+/// Maps on which the guard walks in a loop are discarded, as the puzzle never has any.
+#[aoc_generator(day = 6)]
+pub fn generate(rng: &mut GeneratorRng, size: usize) -> String {
+    let size = size.max(1);
+
+    loop {
+        let mut map = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| {
+                        if rng.gen_bool(OBSTRUCTION_DENSITY) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let guard = (rng.gen_range(0..size), rng.gen_range(0..size));
+        map[guard.1][guard.0] = '^';
+
+        if guard_leaves(&map, guard) {
+            return map
+                .iter()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect();
+        }
+    }
+}
+
+/// Whether the guard starting at `guard` and facing up eventually walks off the map.
+fn guard_leaves(map: &[Vec<char>], guard: (usize, usize)) -> bool {
+    let (mut x, mut y) = guard;
+    let (mut dx, mut dy) = (0_isize, -1_isize);
+    let mut seen = HashSet::new();
+
+    while seen.insert((x, y, dx, dy)) {
+        let Some((next_x, next_y)) = x
+            .checked_add_signed(dx)
+            .zip(y.checked_add_signed(dy))
+            .filter(|(next_x, next_y)| *next_y < map.len() && *next_x < map[*next_y].len())
+        else {
+            return true;
+        };
+
+        if map[next_y][next_x] == '#' {
+            (dx, dy) = (-dy, dx);
+        } else {
+            (x, y) = (next_x, next_y);
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day6;
+
+    solution::generator_tests!(Day6, generate);
+
+    #[test]
+    fn test_guard_leaves() {
+        let map = |rows: &[&str]| {
+            rows.iter()
+                .map(|row| row.chars().collect())
+                .collect::<Vec<_>>()
+        };
+
+        assert!(guard_leaves(&map(&["#..", "..#", ".^."]), (1, 2)));
+        assert!(!guard_leaves(
+            &map(&[".#..", "...#", "#...", ".^#."]),
+            (1, 3)
+        ));
+    }
+}
@@ -0,0 +1,155 @@
+#![warn(clippy::pedantic)]
+
+use crate::map_element::{GuardDirection, MapType};
+use MapType::{Junk, Void};
+use array_utils::grid::Grid;
+use array_utils::{DirectionMove, TableUtils, move_coordinates};
+use file_reader::file_reader::FileReader;
+use file_reader::legend::Legend;
+use map_element::MapElement;
+use solution::Solution;
+use std::collections::HashSet;
+use std::error::Error;
+
+mod generator;
+mod map_element;
+#[cfg(test)]
+mod reference;
+
+/// The characters of the guard, one for each direction it can face.
+const GUARDS: [char; 4] = ['^', '>', 'v', '<'];
+
+type Board = Grid<MapElement>;
+/// The coordinates of the guard and the direction it faces.
+type Guard = ((usize, usize), GuardDirection);
+
+pub struct Day6;
+
+solution::register!(Day6, day = 6);
+
+impl Solution for Day6 {
+    type Input = (Board, Guard);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        let legend = GUARDS.into_iter().fold(
+            Legend::new()
+                .terrain('.', Void(Vec::with_capacity(4)))
+                .terrain('#', Junk),
+            |legend, guard| legend.marker(guard, Void(Vec::with_capacity(4))),
+        );
+        let marked = reader.read_marked_grid(&legend)?;
+
+        let mut guards = GUARDS.into_iter().flat_map(|guard| {
+            marked
+                .positions(guard)
+                .iter()
+                .map(move |coordinates| (*coordinates, GuardDirection::from(guard)))
+        });
+        let (Some((start, direction)), None) = (guards.next(), guards.next()) else {
+            return Err("The board should hold exactly one guard".into());
+        };
+        let guard = ((start.x, start.y), direction);
+
+        let board = marked.grid.map(|mut map_type, coordinates| {
+            // The guard has already crossed its starting tile.
+            if coordinates == start {
+                map_type = Void(vec![direction]);
+            }
+
+            MapElement::new(map_type, coordinates.x, coordinates.y)
+        });
+
+        Ok((board, guard))
+    }
+
+    fn part_one((board, guard): &Self::Input) -> Self::PartOne {
+        let mut board = board.clone();
+        walk_guard(&mut board, *guard);
+
+        board
+            .iter()
+            .flat_map(|row| row.iter())
+            .filter(|map_element| {
+                if let Void(visited_state) = map_element.get_map_type() {
+                    !visited_state.is_empty()
+                } else {
+                    false
+                }
+            })
+            .count()
+    }
+
+    /// Only the tiles the guard walks on can change its patrol, so an obstruction is tried on
+    /// each of them in turn, but the one the guard starts from.
+    fn part_two((board, guard): &Self::Input) -> Self::PartTwo {
+        let mut walked_board = board.clone();
+        walk_guard(&mut walked_board, *guard);
+
+        walked_board
+            .iter()
+            .flat_map(|row| row.iter())
+            .filter(|map_element| {
+                matches!(map_element.get_map_type(), Void(visited_state) if !visited_state.is_empty())
+            })
+            .filter(|map_element| map_element.get_coordinates() != guard.0)
+            .filter(|map_element| walks_in_loop(board, *guard, map_element.get_coordinates()))
+            .count()
+    }
+}
+
+/// Moves the guard until it leaves the board, recording on each tile the directions it was
+/// crossed in.
+fn walk_guard(board: &mut Board, guard: Guard) {
+    let ((mut guard_x, mut guard_y), mut guard_direction) = guard;
+
+    while let Some(next_tile) = board
+        .get_mut_from_coordinates_move((guard_x, guard_y), DirectionMove::from(guard_direction))
+    {
+        match next_tile.get_mut_map_type() {
+            Junk => guard_direction = guard_direction.turn_right(),
+            Void(directions) => {
+                directions.push(guard_direction);
+
+                (guard_x, guard_y) = move_tile(guard_direction, guard_x, guard_y);
+            }
+        }
+    }
+}
+
+/// Whether the guard starting from `guard` walks in a loop once an obstruction is put on the
+/// tile at `obstruction`.
+fn walks_in_loop(board: &Board, guard: Guard, obstruction: (usize, usize)) -> bool {
+    let ((mut guard_x, mut guard_y), mut guard_direction) = guard;
+    // A loop always goes through a turn, so remembering where the guard turned is enough.
+    let mut turns = HashSet::new();
+
+    while let Some(next_tile) =
+        board.get_from_coordinate_move((guard_x, guard_y), DirectionMove::from(guard_direction))
+    {
+        if matches!(next_tile.get_map_type(), Junk) || next_tile.get_coordinates() == obstruction {
+            if !turns.insert((guard_x, guard_y, guard_direction)) {
+                return true;
+            }
+
+            guard_direction = guard_direction.turn_right();
+        } else {
+            (guard_x, guard_y) = next_tile.get_coordinates();
+        }
+    }
+
+    false
+}
+
+fn move_tile(guard_direction: GuardDirection, guard_x: usize, guard_y: usize) -> (usize, usize) {
+    move_coordinates((guard_x, guard_y), guard_direction)
+        .expect("Should find the next tile to move to")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    solution::examples!(Day6);
+}
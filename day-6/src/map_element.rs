@@ -1,20 +1,16 @@
 use GuardDirection::{Down, Left, Right, Up};
-use MapType::{Guard, Junk, Void};
+use MapType::{Junk, Void};
 use array_utils::DirectionMove;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MapElement {
     map_type: MapType,
     x: usize,
     y: usize,
 }
 impl MapElement {
-    pub fn new(map_type: char, x: usize, y: usize) -> Self {
-        MapElement {
-            map_type: MapType::from(map_type),
-            x,
-            y,
-        }
+    pub fn new(map_type: MapType, x: usize, y: usize) -> Self {
+        MapElement { map_type, x, y }
     }
 
     pub fn get_map_type(&self) -> &MapType {
@@ -34,43 +30,21 @@ impl MapElement {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MapType {
     Void(Vec<GuardDirection>), // the tile has already been visited or not
     Junk,
-    Guard(GuardDirection, Vec<GuardDirection>),
 }
 
 impl MapType {
     pub fn get_directions(&self) -> Option<&Vec<GuardDirection>> {
         match self {
-            Void(past_directions) | Guard(_, past_directions) => Some(past_directions),
-            Junk => None,
-        }
-    }
-
-    pub fn get_mut_directions(&mut self) -> Option<&mut Vec<GuardDirection>> {
-        match self {
-            Void(past_directions) | Guard(_, past_directions) => Some(past_directions),
+            Void(past_directions) => Some(past_directions),
             Junk => None,
         }
     }
 }
-impl From<char> for MapType {
-    fn from(character: char) -> Self {
-        match character {
-            '.' => Void(Vec::with_capacity(4)),
-            '#' => Junk,
-            '^' | '>' | 'v' | '<' => {
-                let direction = GuardDirection::from(character);
-                Guard(direction, vec![direction])
-            }
-            _ => panic!("Invalid character to create a map element: {}", character),
-        }
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 pub enum GuardDirection {
     Up,
     Down,
@@ -87,24 +61,6 @@ impl GuardDirection {
             Left => Up,
         }
     }
-
-    pub(crate) fn get_opposed(self) -> GuardDirection {
-        match self {
-            Up => Down,
-            Down => Up,
-            Left => Right,
-            Right => Left,
-        }
-    }
-
-    pub(crate) fn turn_left(self) -> GuardDirection {
-        match self {
-            Up => Left,
-            Left => Down,
-            Down => Right,
-            Right => Up,
-        }
-    }
 }
 
 impl From<char> for GuardDirection {
@@ -114,10 +70,7 @@ impl From<char> for GuardDirection {
             'v' => Down,
             '<' => Left,
             '>' => Right,
-            _ => panic!(
-                "Invalid character to create a guard direction: {}",
-                character
-            ),
+            _ => panic!("Invalid character to create a guard direction: {character}"),
         }
     }
 }
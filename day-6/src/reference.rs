@@ -0,0 +1,86 @@
+//! A slow but obviously correct solution, which the solution of the day is tested against.
+
+use crate::Day6;
+use crate::generator::generate;
+use file_reader::file_reader::FileReader;
+use solution::Solution;
+use std::collections::HashSet;
+use std::error::Error;
+
+pub struct Day6Reference;
+
+impl Solution for Day6Reference {
+    type Input = Vec<Vec<char>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(reader
+            .map(|line| line.trim().chars().collect::<Vec<_>>())
+            .filter(|row| !row.is_empty())
+            .collect())
+    }
+
+    fn part_one(map: &Self::Input) -> Self::PartOne {
+        patrol(map)
+            .expect("The guard leaves the map")
+            .iter()
+            .map(|(x, y, ..)| (x, y))
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Puts an obstruction on every free tile in turn, and walks the whole patrol again.
+    fn part_two(map: &Self::Input) -> Self::PartTwo {
+        let mut loops = 0;
+
+        for y in 0..map.len() {
+            for x in 0..map[y].len() {
+                if map[y][x] == '.' {
+                    let mut map = map.clone();
+                    map[y][x] = '#';
+
+                    if patrol(&map).is_none() {
+                        loops += 1;
+                    }
+                }
+            }
+        }
+
+        loops
+    }
+}
+
+/// Every position and direction of the guard until it leaves the map, or `None` if it walks in a
+/// loop.
+fn patrol(map: &[Vec<char>]) -> Option<HashSet<(usize, usize, isize, isize)>> {
+    let (mut x, mut y) = map
+        .iter()
+        .enumerate()
+        .find_map(|(y, row)| row.iter().position(|tile| *tile == '^').map(|x| (x, y)))
+        .expect("The map has a guard facing up");
+    let (mut step_x, mut step_y) = (0, -1);
+    let mut seen = HashSet::new();
+
+    loop {
+        if !seen.insert((x, y, step_x, step_y)) {
+            return None;
+        }
+
+        let next = x
+            .checked_add_signed(step_x)
+            .zip(y.checked_add_signed(step_y))
+            .filter(|(next_x, next_y)| *next_y < map.len() && *next_x < map[*next_y].len());
+        let Some((next_x, next_y)) = next else {
+            return Some(seen);
+        };
+
+        if map[next_y][next_x] == '#' {
+            (step_x, step_y) = (-step_y, step_x);
+        } else {
+            (x, y) = (next_x, next_y);
+        }
+    }
+}
+
+solution::differential_tests!(Day6, Day6Reference, generate, max_size = 12);
@@ -0,0 +1,4 @@
+// Expands `solution::examples!` again when an example is added or removed.
+fn main() {
+    println!("cargo::rerun-if-changed=src/examples");
+}
@@ -21,6 +21,12 @@ impl Calibration {
             .any(|value| value == self.result)
     }
 
+    /// Same as [`Self::has_matching_expression_second_part`], but works back from the result so
+    /// that operators which can't lead to it are never tried.
+    pub fn has_matching_expression_second_part_pruned(&self) -> bool {
+        self.expression.can_evaluate_to(self.result)
+    }
+
     pub fn get_expression_iter_first_part(&self) -> Box<dyn Iterator<Item = usize> + '_> {
         self.expression.iter_evaluations_first_part()
     }
@@ -62,6 +68,35 @@ impl Expression {
             Expression::Litteral(value) => Box::new(std::iter::once(*value)),
         }
     }
+
+    /// Whether some choice of the operators of the second part evaluates to `target`.
+    ///
+    /// As the outermost operator applies last, each one is undone from the target: a sum needs a
+    /// target at least as large, a product a multiple, and a concatenation a target ending with
+    /// the digits of the number.
+    pub fn can_evaluate_to(&self, target: usize) -> bool {
+        match self {
+            Expression::Expr(left, right) => {
+                (target >= *left && right.can_evaluate_to(target - left))
+                    || (*left != 0
+                        && target.is_multiple_of(*left)
+                        && right.can_evaluate_to(target / left))
+                    || strip_number_suffix(target, *left)
+                        .is_some_and(|target| right.can_evaluate_to(target))
+            }
+            Expression::Litteral(value) => *value == target,
+        }
+    }
+}
+
+/// The number `value` would be concatenated to in order to give `number`, if any.
+fn strip_number_suffix(number: usize, value: usize) -> Option<usize> {
+    let mut power = 10;
+    while power <= value {
+        power *= 10;
+    }
+
+    (number % power == value).then_some(number / power)
 }
 
 fn concat_numbers(a: usize, b: usize) -> usize {
@@ -79,4 +114,13 @@ mod test {
         assert_eq!(concat_numbers(123, 4), 1234);
         assert_eq!(concat_numbers(1234, 5), 12345);
     }
+
+    #[test]
+    fn test_strip_number_suffix() {
+        assert_eq!(strip_number_suffix(12345, 45), Some(123));
+        assert_eq!(strip_number_suffix(12345, 5), Some(1234));
+        assert_eq!(strip_number_suffix(12345, 0), None);
+        assert_eq!(strip_number_suffix(100, 0), Some(10));
+        assert_eq!(strip_number_suffix(12345, 12345), Some(0));
+    }
 }
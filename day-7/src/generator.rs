@@ -0,0 +1,73 @@
+use aoc_macros::aoc_generator;
+use rand::Rng;
+use solution::generator::GeneratorRng;
+use std::fmt::Write;
+
+/// Most digits of all the numbers of an equation, so that no evaluation of it overflows: neither
+/// a sum nor a product of two numbers has more digits than their concatenation.
+const MAX_DIGITS: u32 = 18;
+
+/// Generates `lines` calibration equations of 2 to 9 numbers. A third of them is solvable with
+/// additions and multiplications, a third also needs concatenations, and the rest is most
+/// likely unsolvable.
+#[aoc_generator(day = 7)]
+pub fn generate(rng: &mut GeneratorRng, lines: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..lines {
+        let mut numbers = Vec::new();
+        let mut digits = 0;
+
+        while numbers.len() < 2 || (numbers.len() < 9 && rng.gen_bool(0.8)) {
+            let number = rng.gen_range(1_usize..1000);
+            let number_digits = number.ilog10() + 1;
+
+            if digits + number_digits > MAX_DIGITS {
+                break;
+            }
+            digits += number_digits;
+            numbers.push(number);
+        }
+
+        let operators = rng.gen_range(0..3);
+        let mut result = numbers[0];
+        for number in &numbers[1..] {
+            result = match rng.gen_range(0..if operators == 2 { 3 } else { 2 }) {
+                0 => result + number,
+                1 => result * number,
+                _ => format!("{result}{number}")
+                    .parse()
+                    .expect("The concatenation fits"),
+            };
+        }
+        if operators == 0 {
+            result += 1;
+        }
+
+        let numbers = numbers.iter().map(ToString::to_string).collect::<Vec<_>>();
+        writeln!(input, "{result}: {}", numbers.join(" ")).expect("Writing to a string can't fail");
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day7;
+    use file_reader::file_reader::FileReader;
+    use solution::Solution;
+
+    solution::generator_tests!(Day7, generate);
+
+    #[test]
+    fn test_generates_equations_needing_concatenations() {
+        let input = generate(&mut solution::generator::rng(0), 100);
+        let calibrations = Day7::parse(FileReader::from_string(input)).unwrap();
+
+        assert!(calibrations.iter().any(|calibration| {
+            !calibration.has_matching_expression_first_part()
+                && calibration.has_matching_expression_second_part()
+        }));
+    }
+}
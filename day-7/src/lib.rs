@@ -0,0 +1,109 @@
+#![warn(clippy::pedantic)]
+#![allow(dead_code)]
+
+mod calibration;
+mod generator;
+#[cfg(test)]
+mod reference;
+
+use crate::calibration::{Calibration, Expression};
+use aoc_macros::aoc;
+use file_reader::file_reader::FileReader;
+use file_reader::parse::{FieldError, Line};
+use num_bigint::BigUint;
+use solution::Solution;
+use std::error::Error;
+
+pub struct Day7;
+
+solution::register!(Day7, day = 7);
+
+impl Solution for Day7 {
+    type Input = Vec<Calibration>;
+    type PartOne = usize;
+    type PartTwo = BigUint;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(reader.parse_lines_with(parse_line)?)
+    }
+
+    fn part_one(calibrations: &Self::Input) -> Self::PartOne {
+        calibrations
+            .iter()
+            .filter(|calibration| calibration.has_matching_expression_first_part())
+            .map(Calibration::get_result)
+            .sum::<usize>()
+    }
+
+    fn part_two(calibrations: &Self::Input) -> Self::PartTwo {
+        calibrations
+            .iter()
+            .filter(|calibration| calibration.has_matching_expression_second_part())
+            .map(Calibration::get_result)
+            .sum::<BigUint>()
+    }
+}
+
+/// Same answer as the second part, but prunes the operators which can't lead to the result.
+#[aoc(day = 7, part = 2, name = "pruned")]
+fn part_two_pruned(calibrations: &[Calibration]) -> BigUint {
+    calibrations
+        .iter()
+        .filter(|calibration| calibration.has_matching_expression_second_part_pruned())
+        .map(Calibration::get_result)
+        .sum::<BigUint>()
+}
+
+fn parse_line(line: Line) -> Result<Calibration, FieldError> {
+    let (value, expression) = line
+        .text()
+        .split_once(':')
+        .ok_or_else(|| line.missing("`:` after the result value"))?;
+
+    let numbers = expression
+        .split_whitespace()
+        .rev()
+        .map(|number| line.parse::<usize>(number))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if numbers.is_empty() {
+        return Err(line.missing("expression"));
+    }
+
+    Ok(Calibration::new(
+        line.parse::<usize>(value.trim())?,
+        parse_expr(&numbers),
+    ))
+}
+
+fn parse_expr(expression: &[usize]) -> Expression {
+    let number = expression[0];
+
+    if expression.len() > 1 {
+        let expr = parse_expr(&expression[1..]);
+
+        return Expression::Expr(number, Box::new(expr));
+    }
+
+    Expression::Litteral(number)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    solution::examples!(Day7);
+
+    #[test]
+    fn test_pruned_part_two() -> Result<(), Box<dyn Error>> {
+        let path_file = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input");
+        let calibrations = Day7::parse(FileReader::new(path_file)?)?;
+
+        assert_eq!(
+            part_two_pruned(&calibrations),
+            Day7::part_two(&calibrations)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+//! A slow but obviously correct solution, which the solution of the day is tested against.
+
+use crate::Day7;
+use crate::generator::generate;
+use file_reader::file_reader::FileReader;
+use solution::Solution;
+use std::error::Error;
+
+pub struct Day7Reference;
+
+impl Solution for Day7Reference {
+    type Input = Vec<(u128, Vec<u128>)>;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        reader
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let (result, numbers) = line.split_once(':').ok_or_else(|| {
+                    format!("Expected `<result>: <numbers>`, got `{}`", line.trim())
+                })?;
+                let numbers = numbers
+                    .split_whitespace()
+                    .map(str::parse)
+                    .collect::<Result<_, _>>()?;
+
+                Ok((result.trim().parse()?, numbers))
+            })
+            .collect()
+    }
+
+    fn part_one(equations: &Self::Input) -> Self::PartOne {
+        total_calibration(equations, 2)
+    }
+
+    fn part_two(equations: &Self::Input) -> Self::PartTwo {
+        total_calibration(equations, 3)
+    }
+}
+
+/// Sums the results of the equations which some combination of the first `operators` operators
+/// (addition, multiplication, concatenation) solves, trying every combination.
+fn total_calibration(equations: &[(u128, Vec<u128>)], operators: u32) -> u128 {
+    equations
+        .iter()
+        .filter(|(result, numbers)| {
+            let Some((first, rest)) = numbers.split_first() else {
+                return false;
+            };
+            let exponent = u32::try_from(rest.len()).expect("An equation has few numbers");
+
+            (0..operators.pow(exponent)).any(|mut combination| {
+                let value = rest.iter().fold(*first, |value, number| {
+                    let operator = combination % operators;
+                    combination /= operators;
+
+                    match operator {
+                        0 => value + number,
+                        1 => value * number,
+                        _ => format!("{value}{number}")
+                            .parse()
+                            .expect("Concatenated digits"),
+                    }
+                });
+
+                value == *result
+            })
+        })
+        .map(|(result, _)| result)
+        .sum()
+}
+
+solution::differential_tests!(Day7, Day7Reference, generate, max_size = 10);
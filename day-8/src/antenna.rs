@@ -51,12 +51,17 @@ fn calculate_coordinates_with_repetition(
     if antenna_coordinates.len() > 1 {
         let current_coordinates = antenna_coordinates[0];
 
-        for coordinate in antenna_coordinates[1..].iter() {
-            let x_diff = coordinate.0 - current_coordinates.0;
-            let y_diff = coordinate.1 - current_coordinates.1;
+        for coordinate in &antenna_coordinates[1..] {
+            // Steps to the closest tile in line with both antennas, which may lie between them.
+            let divisor = gcd(
+                coordinate.0 - current_coordinates.0,
+                coordinate.1 - current_coordinates.1,
+            );
+            let x_diff = (coordinate.0 - current_coordinates.0) / divisor;
+            let y_diff = (coordinate.1 - current_coordinates.1) / divisor;
 
-            let mut x_extrapolated = coordinate.0;
-            let mut y_extrapolated = coordinate.1;
+            let mut x_extrapolated = current_coordinates.0 + x_diff;
+            let mut y_extrapolated = current_coordinates.1 + y_diff;
 
             while (0..max_x).contains(&x_extrapolated) && (0..max_y).contains(&y_extrapolated) {
                 extrapolated_coordinates.push((x_extrapolated, y_extrapolated));
@@ -85,6 +90,10 @@ fn calculate_coordinates_with_repetition(
     }
 }
 
+fn gcd(a: isize, b: isize) -> isize {
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
 fn calculate_coordinates(
     extrapolated_coordinates: &mut Vec<(isize, isize)>,
     antenna_coordinates: &[(isize, isize)],
@@ -92,7 +101,7 @@ fn calculate_coordinates(
     if antenna_coordinates.len() > 1 {
         let current_coordinates = antenna_coordinates[0];
 
-        for coordinate in antenna_coordinates[1..].iter() {
+        for coordinate in &antenna_coordinates[1..] {
             let x_diff = coordinate.0 - current_coordinates.0;
             let y_diff = coordinate.1 - current_coordinates.1;
 
@@ -0,0 +1,39 @@
+use aoc_macros::aoc_generator;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use solution::generator::GeneratorRng;
+
+/// The characters antennas are tuned to.
+const FREQUENCIES: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Generates a square map of `size` by `size` tiles, with groups of 2 to 4 antennas tuned to a
+/// same frequency, about one group every 5 tiles of side.
+#[aoc_generator(day = 8)]
+pub fn generate(rng: &mut GeneratorRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut map = vec![vec!['.'; size]; size];
+    let mut free_tiles = (0..size * size).collect::<Vec<_>>();
+    free_tiles.shuffle(rng);
+
+    let frequencies = FREQUENCIES.chars().collect::<Vec<_>>();
+    for frequency in frequencies.choose_multiple(rng, (size / 5).max(1)) {
+        for _ in 0..rng.gen_range(2..=4) {
+            let Some(tile) = free_tiles.pop() else {
+                break;
+            };
+            map[tile / size][tile % size] = *frequency;
+        }
+    }
+
+    map.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day8;
+
+    solution::generator_tests!(Day8, generate);
+}
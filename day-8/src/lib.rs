@@ -0,0 +1,76 @@
+#![warn(clippy::pedantic)]
+
+mod antenna;
+mod generator;
+#[cfg(test)]
+mod reference;
+
+use crate::antenna::AntennaCoordinates;
+use file_reader::file_reader::FileReader;
+use file_reader::legend::Legend;
+use itertools::Itertools;
+use solution::Solution;
+use std::collections::HashMap;
+use std::error::Error;
+
+type ParsingData = (HashMap<char, AntennaCoordinates>, isize, isize);
+
+pub struct Day8;
+
+solution::register!(Day8, day = 8);
+
+impl Solution for Day8 {
+    type Input = ParsingData;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        let marked = reader.read_marked_grid(&Legend::new().terrain('.', ()).other_markers(()))?;
+
+        let antennas_coordinates = marked
+            .markers
+            .into_iter()
+            .map(|(frequency, positions)| {
+                let mut antenna_coordinates = AntennaCoordinates::new();
+                for coordinates in positions {
+                    antenna_coordinates
+                        .add_coordinates((coordinates.x as isize, coordinates.y as isize));
+                }
+
+                (frequency, antenna_coordinates)
+            })
+            .collect();
+
+        let max_x = marked.grid.width() as isize;
+        let max_y = marked.grid.height() as isize;
+
+        Ok((antennas_coordinates, max_x, max_y))
+    }
+
+    fn part_one((antennas_coordinates, max_x, max_y): &Self::Input) -> Self::PartOne {
+        antennas_coordinates
+            .values()
+            .flat_map(AntennaCoordinates::get_extrapolated_coordinates)
+            .filter(|(x, y)| (0..*max_x).contains(x) && (0..*max_y).contains(y))
+            .unique()
+            .count()
+    }
+
+    fn part_two((antennas_coordinates, max_x, max_y): &Self::Input) -> Self::PartTwo {
+        antennas_coordinates
+            .values()
+            .flat_map(|coordinates| {
+                coordinates.get_extrapolated_coordinates_with_repetition(*max_x, *max_y)
+            })
+            .unique()
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    solution::examples!(Day8);
+}
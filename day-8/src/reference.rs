@@ -0,0 +1,77 @@
+//! A slow but obviously correct solution, which the solution of the day is tested against.
+
+use crate::Day8;
+use crate::generator::generate;
+use file_reader::file_reader::FileReader;
+use solution::Solution;
+use std::error::Error;
+
+pub struct Day8Reference;
+
+type Antenna = (char, isize, isize);
+
+impl Solution for Day8Reference {
+    type Input = (Vec<Antenna>, isize, isize);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+        let rows = reader
+            .map(|line| line.trim().chars().collect::<Vec<_>>())
+            .filter(|row| !row.is_empty())
+            .collect::<Vec<_>>();
+        let mut antennas = Vec::new();
+
+        for (y, row) in (0..).zip(&rows) {
+            for (x, tile) in (0..).zip(row) {
+                if *tile != '.' {
+                    antennas.push((*tile, x, y));
+                }
+            }
+        }
+
+        let width = rows.first().map_or(0, Vec::len);
+
+        Ok((
+            antennas,
+            isize::try_from(width)?,
+            isize::try_from(rows.len())?,
+        ))
+    }
+
+    /// Checks every tile against every pair of antennas, for being twice as far from one as from
+    /// the other while in line with both.
+    fn part_one((antennas, width, height): &Self::Input) -> Self::PartOne {
+        count_antinodes(antennas, *width, *height, |(x, y), first, second| {
+            (x - first.1, y - first.2) == (2 * (x - second.1), 2 * (y - second.2))
+        })
+    }
+
+    /// Checks every tile against every pair of antennas, for being in line with both.
+    fn part_two((antennas, width, height): &Self::Input) -> Self::PartTwo {
+        count_antinodes(antennas, *width, *height, |(x, y), first, second| {
+            (second.1 - first.1) * (y - first.2) == (second.2 - first.2) * (x - first.1)
+        })
+    }
+}
+
+fn count_antinodes(
+    antennas: &[Antenna],
+    width: isize,
+    height: isize,
+    is_antinode: impl Fn((isize, isize), &Antenna, &Antenna) -> bool,
+) -> usize {
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .filter(|tile| {
+            antennas.iter().any(|first| {
+                antennas
+                    .iter()
+                    .filter(|second| second.0 == first.0 && second != &first)
+                    .any(|second| is_antinode(*tile, first, second))
+            })
+        })
+        .count()
+}
+
+solution::differential_tests!(Day8, Day8Reference, generate);
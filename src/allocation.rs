@@ -0,0 +1,171 @@
+//! Heap allocation tracking, enabled with the `alloc-stats` feature.
+//!
+//! The feature installs a global allocator counting, for the current thread, the allocations
+//! made, the bytes allocated and the bytes still live. As each day runs on a single thread,
+//! these counters are exact for a day even when several days run concurrently. Without the
+//! feature, [`measure`] reports nothing.
+
+#[cfg(feature = "alloc-stats")]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Whether the counting allocator is installed.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// The allocations made while running one phase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// Number of allocations, reallocations included.
+    pub count: u64,
+    /// Total number of bytes allocated.
+    pub bytes: u64,
+    /// Highest number of bytes live at once, on top of those live when the phase started.
+    pub peak_live_bytes: u64,
+}
+
+/// Runs `phase`, counting the allocations it makes on the current thread.
+///
+/// Returns `None` for the statistics when the `alloc-stats` feature is disabled.
+pub fn measure<R>(phase: impl FnOnce() -> R) -> (R, Option<AllocationStats>) {
+    if !ENABLED {
+        return (phase(), None);
+    }
+
+    let start = Counters::current();
+    COUNTERS.with(|counters| counters.peak_live_bytes.set(start.live_bytes));
+
+    let result = phase();
+
+    let end = Counters::current();
+    let stats = AllocationStats {
+        count: end.count - start.count,
+        bytes: end.bytes - start.bytes,
+        peak_live_bytes: end.peak_live_bytes.saturating_sub(start.live_bytes),
+    };
+
+    (result, Some(stats))
+}
+
+/// Forwards to the system allocator, counting on the way.
+#[cfg(feature = "alloc-stats")]
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc-stats")]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation(layout.size());
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc`.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record_deallocation(layout.size());
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::dealloc`.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_allocation(layout.size());
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc_zeroed`.
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_deallocation(layout.size());
+        record_allocation(new_size);
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::realloc`.
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// Counters of the current thread. They hold plain integers, so that reading and updating them
+/// never allocates.
+struct ThreadCounters {
+    count: Cell<u64>,
+    bytes: Cell<u64>,
+    live_bytes: Cell<u64>,
+    peak_live_bytes: Cell<u64>,
+}
+
+thread_local! {
+    static COUNTERS: ThreadCounters = const {
+        ThreadCounters {
+            count: Cell::new(0),
+            bytes: Cell::new(0),
+            live_bytes: Cell::new(0),
+            peak_live_bytes: Cell::new(0),
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    count: u64,
+    bytes: u64,
+    live_bytes: u64,
+    peak_live_bytes: u64,
+}
+
+impl Counters {
+    fn current() -> Counters {
+        COUNTERS.with(|counters| Counters {
+            count: counters.count.get(),
+            bytes: counters.bytes.get(),
+            live_bytes: counters.live_bytes.get(),
+            peak_live_bytes: counters.peak_live_bytes.get(),
+        })
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+fn record_allocation(size: usize) {
+    // The counters may already be gone while a thread shuts down, its last allocations are
+    // then left uncounted.
+    let _ = COUNTERS.try_with(|counters| {
+        let size = size as u64;
+        let live_bytes = counters.live_bytes.get() + size;
+
+        counters.count.set(counters.count.get() + 1);
+        counters.bytes.set(counters.bytes.get() + size);
+        counters.live_bytes.set(live_bytes);
+        counters
+            .peak_live_bytes
+            .set(counters.peak_live_bytes.get().max(live_bytes));
+    });
+}
+
+#[cfg(feature = "alloc-stats")]
+fn record_deallocation(size: usize) {
+    // Memory freed by another thread than the one that allocated it would make the live bytes
+    // of this thread go below zero.
+    let _ = COUNTERS.try_with(|counters| {
+        counters
+            .live_bytes
+            .set(counters.live_bytes.get().saturating_sub(size as u64));
+    });
+}
+
+#[cfg(all(test, feature = "alloc-stats"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_measure_allocations() {
+        let (vector, stats) = measure(|| {
+            let temporary = vec![0_u8; 4096];
+            drop(temporary);
+
+            Vec::<u64>::with_capacity(16)
+        });
+        let stats = stats.expect("The feature is enabled");
+
+        assert_eq!(2, stats.count);
+        assert_eq!(4096 + 16 * 8, stats.bytes);
+        assert_eq!(4096, stats.peak_live_bytes);
+        drop(vector);
+    }
+}
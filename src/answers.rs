@@ -0,0 +1,236 @@
+use solution::answer::Answer;
+use solution::Part;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use toml_edit::{value, DocumentMut, Item, Table, Value};
+
+/// Path to the expected answers file at the root of the workspace.
+pub const ANSWERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+/// Known answers, keyed by day, input name and part.
+///
+/// The file is a TOML document with one table per day and input, for example:
+/// ```toml
+/// [day-5.input]
+/// part-one = 4924
+/// part-two = "6085"
+/// ```
+/// Answers may be written as integers or as strings; strings are needed for integers that do
+/// not fit in 64 bits.
+pub struct ExpectedAnswers {
+    document: DocumentMut,
+}
+
+impl ExpectedAnswers {
+    /// Loads the answers file at `path`, treating a missing file as an empty one.
+    pub fn load(path: &Path) -> Result<ExpectedAnswers, Box<dyn Error>> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(Box::new(err)),
+        };
+
+        Ok(ExpectedAnswers {
+            document: content.parse()?,
+        })
+    }
+
+    pub fn get(&self, day: u8, input_name: &str, part: Part) -> Option<Answer> {
+        let item = self
+            .document
+            .get(&day_key(day))?
+            .get(input_name)?
+            .get(part.key())?;
+
+        match item {
+            Item::Value(Value::Integer(number)) => Some(Answer::from(*number.value())),
+            Item::Value(Value::String(text)) => text.value().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Records `answer` as the expected answer, replacing any previous one.
+    pub fn set(&mut self, day: u8, input_name: &str, part: Part, answer: &Answer) {
+        let day_table = self
+            .document
+            .entry(&day_key(day))
+            .or_insert_with(|| {
+                let mut table = Table::new();
+                table.set_implicit(true);
+                Item::Table(table)
+            })
+            .as_table_mut()
+            .expect("Days are stored as tables");
+        let input_table = day_table
+            .entry(input_name)
+            .or_insert_with(|| Item::Table(Table::new()))
+            .as_table_mut()
+            .expect("Inputs are stored as tables");
+
+        input_table[part.key()] = match answer {
+            Answer::Unsigned(number) => match i64::try_from(*number) {
+                Ok(number) => value(number),
+                Err(_) => value(number.to_string()),
+            },
+            Answer::Signed(number) => value(*number),
+            Answer::Big(_) | Answer::Text(_) => value(answer.to_string()),
+        };
+    }
+
+    /// Writes the answers back to `path`, keeping the formatting and comments of the file.
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, self.document.to_string())?;
+
+        Ok(())
+    }
+
+    pub fn verify(&self, day: u8, input_name: &str, part: Part, answer: &Answer) -> Verification {
+        Verification::against(self.get(day, input_name, part), answer)
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day-{day}")
+}
+
+/// The outcome of checking an answer against the expected answers file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    Correct,
+    Wrong(Answer),
+    Unknown,
+}
+
+impl Verification {
+    /// Checks `answer` against the `expected` one, when there is one.
+    pub fn against(expected: Option<Answer>, answer: &Answer) -> Verification {
+        match expected {
+            Some(expected) if expected == *answer => Verification::Correct,
+            Some(expected) => Verification::Wrong(expected),
+            None => Verification::Unknown,
+        }
+    }
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verification::Correct => write!(f, "correct"),
+            Verification::Wrong(expected) => write!(f, "wrong, expected {expected}"),
+            Verification::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::input::DEFAULT_INPUT_NAME;
+    use file_reader::file_reader::FileReader;
+
+    fn expected_answers(content: &str) -> ExpectedAnswers {
+        ExpectedAnswers {
+            document: content.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_get_answer() {
+        let answers = expected_answers(
+            r#"
+            [day-5.input]
+            part-one = 4924
+            part-two = "6085"
+            "#,
+        );
+
+        assert_eq!(Some(Answer::from(4924)), answers.get(5, "input", Part::One));
+        assert_eq!(Some(Answer::from(6085)), answers.get(5, "input", Part::Two));
+        assert_eq!(None, answers.get(5, "test", Part::One));
+        assert_eq!(None, answers.get(6, "input", Part::One));
+    }
+
+    #[test]
+    fn test_verify_answer() {
+        let answers = expected_answers(
+            r#"
+            [day-6.input]
+            part-one = 4647
+            "#,
+        );
+
+        assert_eq!(
+            Verification::Correct,
+            answers.verify(6, "input", Part::One, &Answer::from(4647))
+        );
+        assert_eq!(
+            Verification::Wrong(Answer::from(4647)),
+            answers.verify(6, "input", Part::One, &Answer::from(4646))
+        );
+        assert_eq!(
+            Verification::Unknown,
+            answers.verify(6, "input", Part::Two, &Answer::from(234))
+        );
+    }
+
+    #[test]
+    fn test_set_answer() {
+        let mut answers = expected_answers(
+            r#"
+            # Answers of day 5
+            [day-5.input]
+            part-one = 4924
+            "#,
+        );
+
+        answers.set(5, "input", Part::Two, &Answer::from(6085));
+        answers.set(7, "input", Part::One, &Answer::from(u64::MAX));
+        let reloaded = expected_answers(&answers.document.to_string());
+
+        assert!(reloaded.document.to_string().contains("# Answers of day 5"));
+        assert!(reloaded.document.to_string().contains("[day-7.input]"));
+        assert_eq!(
+            Some(Answer::from(4924)),
+            reloaded.get(5, "input", Part::One)
+        );
+        assert_eq!(
+            Some(Answer::from(6085)),
+            reloaded.get(5, "input", Part::Two)
+        );
+        assert_eq!(
+            Some(Answer::from(u64::MAX)),
+            reloaded.get(7, "input", Part::One)
+        );
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let answers = ExpectedAnswers::load(Path::new("missing_answers.toml")).unwrap();
+
+        assert_eq!(None, answers.get(1, "input", Part::One));
+    }
+
+    /// The single source of the answers of the puzzle inputs shipped with the days.
+    #[test]
+    fn test_days_give_their_expected_answers() -> Result<(), Box<dyn Error>> {
+        let answers = ExpectedAnswers::load(Path::new(ANSWERS))?;
+
+        for day in crate::days::all() {
+            let run = day.run(FileReader::new(day.input)?, day.select(None)?)?;
+
+            for (part, answer) in Part::ALL.into_iter().zip([run.part_one, run.part_two]) {
+                assert_eq!(
+                    Verification::Correct,
+                    answers.verify(day.number, DEFAULT_INPUT_NAME, part, &answer),
+                    "{part} of day {} gave {answer}",
+                    day.number
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
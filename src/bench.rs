@@ -0,0 +1,361 @@
+//! Benchmarks of the parsing and both parts of every day, compared against a stored baseline.
+//!
+//! A change against the baseline is only reported as a regression or an improvement when it is
+//! both larger than the threshold and statistically significant, according to Welch's t-test
+//! on the means of the samples.
+
+use crate::cli::BenchArgs;
+use crate::days::{self, Day, DayRun};
+use crate::input::{InputResolver, InputSource};
+use crate::runner::NoDaySelected;
+use crate::timing::{Micros, Statistics};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Default location of the baseline, next to the build artifacts as it only holds for the
+/// machine it was measured on.
+pub const BASELINE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/target/aoc-bench/baseline.json"
+);
+/// Version of the baseline file format.
+pub const BASELINE_VERSION: u32 = 1;
+
+/// Runs discarded before sampling, so that caches and the allocator are warm.
+const WARMUP_RUNS: usize = 2;
+/// Value of Welch's t statistic above which a difference is significant. Higher than the usual
+/// critical values, as timings are far from normally distributed.
+const SIGNIFICANT_T: f64 = 3.0;
+
+/// Benchmarks the selected days, compares them against the baseline when there is one, and
+/// saves them as the new baseline when asked to.
+pub fn bench(args: &BenchArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let selection = args.selection();
+    let days = days::all()
+        .into_iter()
+        .filter(|day| selection.contains(day.number))
+        .collect::<Vec<_>>();
+
+    if days.is_empty() {
+        return Err(Box::new(NoDaySelected));
+    }
+
+    let baseline = Baseline::load(&args.baseline)?;
+    let resolver = InputResolver::from_env(None).with_mmap(args.mmap);
+    let mut measurements = Vec::new();
+    let mut regressed = false;
+
+    for day in days {
+        let input = resolver
+            .resolve(day.number, Path::new(day.input))?
+            .source()?;
+
+        for measurement in measure_day(&day, &input, args.samples as usize)? {
+            let previous = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.find(measurement.day, measurement.phase));
+            let change =
+                previous.map(|previous| Change::between(previous, &measurement, args.threshold));
+
+            regressed |= matches!(change, Some(Change::Regressed(_)));
+            println!("{}", render_line(&measurement, previous, change));
+            measurements.push(measurement);
+        }
+    }
+
+    if args.save_baseline {
+        Baseline {
+            version: BASELINE_VERSION,
+            measurements,
+        }
+        .save(&args.baseline)?;
+        println!("Baseline saved to {}", args.baseline.display());
+    }
+
+    Ok(if regressed && args.fail_on_regression {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// Runs a day repeatedly on `input`, timing each of its phases.
+fn measure_day(
+    day: &Day,
+    input: &InputSource,
+    samples: usize,
+) -> Result<[Measurement; 3], Box<dyn Error>> {
+    let solvers = day.select(None)?;
+    let runs = (0..WARMUP_RUNS + samples.max(1))
+        .map(|_| day.run(input.reader()?, solvers))
+        .collect::<Result<Vec<_>, _>>()?;
+    let runs = &runs[WARMUP_RUNS..];
+
+    Ok(Phase::ALL.map(|phase| {
+        let samples = runs.iter().map(|run| phase.time(run)).collect::<Vec<_>>();
+        let statistics =
+            Statistics::from_samples(&samples).expect("A day is always run at least once");
+
+        Measurement::new(day.number, phase, &statistics)
+    }))
+}
+
+/// A timed phase of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Phase {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::PartOne, Phase::PartTwo];
+
+    fn time(self, run: &DayRun) -> Duration {
+        match self {
+            Phase::Parse => run.parse_time,
+            Phase::PartOne => run.part_one_time,
+            Phase::PartTwo => run.part_two_time,
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => f.pad("parse"),
+            Phase::PartOne => f.pad("part one"),
+            Phase::PartTwo => f.pad("part two"),
+        }
+    }
+}
+
+/// The timings of one phase of one day, as stored in the baseline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: Phase,
+    pub samples: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Measurement {
+    fn new(day: u8, phase: Phase, statistics: &Statistics) -> Measurement {
+        let nanos = |duration: Duration| u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+
+        Measurement {
+            day,
+            phase,
+            samples: statistics.runs,
+            min_ns: nanos(statistics.min),
+            median_ns: nanos(statistics.median),
+            mean_ns: nanos(statistics.mean),
+            stddev_ns: nanos(statistics.stddev),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Baseline {
+    version: u32,
+    measurements: Vec<Measurement>,
+}
+
+impl Baseline {
+    /// Loads the baseline at `path`, or `None` if there is none yet.
+    fn load(path: &Path) -> Result<Option<Baseline>, Box<dyn Error>> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(Box::new(err)),
+        };
+        let baseline = serde_json::from_str::<Baseline>(&content)?;
+
+        if baseline.version != BASELINE_VERSION {
+            return Err(format!(
+                "Unsupported baseline version {} in {}, expected {BASELINE_VERSION}",
+                baseline.version,
+                path.display()
+            )
+            .into());
+        }
+
+        Ok(Some(baseline))
+    }
+
+    fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+
+        Ok(())
+    }
+
+    fn find(&self, day: u8, phase: Phase) -> Option<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|measurement| measurement.day == day && measurement.phase == phase)
+    }
+}
+
+/// How a measurement compares to its baseline, with the relative change of the mean in percent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    Regressed(f64),
+    Improved(f64),
+    Unchanged(f64),
+}
+
+impl Change {
+    /// Compares the means of two measurements, only reporting a change larger than `threshold`
+    /// percent that Welch's t-test deems significant.
+    pub fn between(baseline: &Measurement, current: &Measurement, threshold: f64) -> Change {
+        let baseline_mean = baseline.mean_ns as f64;
+        let current_mean = current.mean_ns as f64;
+        let relative_change = if baseline_mean > 0.0 {
+            (current_mean / baseline_mean - 1.0) * 100.0
+        } else {
+            0.0
+        };
+
+        let standard_error = (variance_of_mean(baseline) + variance_of_mean(current)).sqrt();
+        let significant = if standard_error > 0.0 {
+            ((current_mean - baseline_mean) / standard_error).abs() > SIGNIFICANT_T
+        } else {
+            current_mean != baseline_mean
+        };
+
+        if !significant || relative_change.abs() <= threshold {
+            Change::Unchanged(relative_change)
+        } else if relative_change > 0.0 {
+            Change::Regressed(relative_change)
+        } else {
+            Change::Improved(relative_change)
+        }
+    }
+}
+
+fn variance_of_mean(measurement: &Measurement) -> f64 {
+    (measurement.stddev_ns as f64).powi(2) / measurement.samples.max(1) as f64
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Regressed(change) => write!(f, "{change:+.1}% regressed"),
+            Change::Improved(change) => write!(f, "{change:+.1}% improved"),
+            Change::Unchanged(change) => write!(f, "{change:+.1}% no significant change"),
+        }
+    }
+}
+
+fn render_line(
+    measurement: &Measurement,
+    previous: Option<&Measurement>,
+    change: Option<Change>,
+) -> String {
+    let micros = |nanos: u64| Micros(Duration::from_nanos(nanos));
+    let mut line = format!(
+        "Day {:<2} {:<8} mean {} ± {} | median {}",
+        measurement.day,
+        measurement.phase,
+        micros(measurement.mean_ns),
+        micros(measurement.stddev_ns),
+        micros(measurement.median_ns)
+    );
+
+    match (previous, change) {
+        (Some(previous), Some(change)) => {
+            line.push_str(&format!(
+                " | baseline {} | {change}",
+                micros(previous.mean_ns)
+            ));
+        }
+        _ => line.push_str(" | no baseline"),
+    }
+
+    line
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn measurement(mean_ns: u64, stddev_ns: u64) -> Measurement {
+        Measurement {
+            day: 1,
+            phase: Phase::PartOne,
+            samples: 20,
+            min_ns: mean_ns,
+            median_ns: mean_ns,
+            mean_ns,
+            stddev_ns,
+        }
+    }
+
+    #[test]
+    fn test_significant_changes() {
+        let baseline = measurement(1000, 10);
+
+        assert!(matches!(
+            Change::between(&baseline, &measurement(1200, 10), 2.0),
+            Change::Regressed(change) if (change - 20.0).abs() < 1e-9
+        ));
+        assert!(matches!(
+            Change::between(&baseline, &measurement(800, 10), 2.0),
+            Change::Improved(change) if (change + 20.0).abs() < 1e-9
+        ));
+    }
+
+    #[test]
+    fn test_noise_is_not_a_change() {
+        let baseline = measurement(1000, 400);
+
+        // Large but within the noise of the samples.
+        assert!(matches!(
+            Change::between(&baseline, &measurement(1200, 400), 2.0),
+            Change::Unchanged(_)
+        ));
+        // Significant but below the threshold.
+        assert!(matches!(
+            Change::between(&measurement(1000, 1), &measurement(1010, 1), 2.0),
+            Change::Unchanged(_)
+        ));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() -> Result<(), Box<dyn Error>> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("nested").join("baseline.json");
+
+        assert!(Baseline::load(&path)?.is_none());
+
+        Baseline {
+            version: BASELINE_VERSION,
+            measurements: vec![measurement(1000, 10)],
+        }
+        .save(&path)?;
+        let baseline = Baseline::load(&path)?.expect("The baseline was just saved");
+
+        assert_eq!(
+            Some(&measurement(1000, 10)),
+            baseline.find(1, Phase::PartOne)
+        );
+        assert_eq!(None, baseline.find(1, Phase::Parse));
+        assert!(fs::read_to_string(&path)?.contains("\"phase\": \"part-one\""));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,373 @@
+use crate::answers::ANSWERS;
+use crate::bench::BASELINE;
+use crate::client::ClientSettings;
+use crate::config::Config;
+use crate::output::OutputFormat;
+use crate::timing::{parse_duration, Repetition};
+use clap::{Args, Parser, Subcommand};
+use solution::Part;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2024 solutions")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Solves the selected days and prints both parts
+    Run(RunArgs),
+    /// Downloads the input of the selected days into the cache
+    Fetch(FetchArgs),
+    /// Solves one part of a day on its input and submits the answer
+    Submit(SubmitArgs),
+    /// Generates the crate of a new day and registers it in the workspace
+    New(NewArgs),
+    /// Rebuilds and re-runs a day whenever its sources or input change
+    Watch(WatchArgs),
+    /// Benchmarks the selected days and compares them against the stored baseline
+    Bench(BenchArgs),
+    /// Runs every implementation of each part of a day and compares their answers and timings
+    Compare(CompareArgs),
+    /// Generates a random input of a day
+    Generate(GenerateArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct RunArgs {
+    /// Day or range of days to run, e.g. `5`, `1..=8` or `3..6`
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    days: Option<DaySelection>,
+
+    /// Runs every available day
+    #[arg(long)]
+    all: bool,
+
+    /// Input file to run the day on instead of its default input, or `-` to read stdin
+    #[arg(long, value_name = "PATH|-")]
+    pub input: Option<PathBuf>,
+
+    /// Reads the input as an example, with its expected answers, even outside an `examples`
+    /// directory
+    #[arg(long)]
+    pub example: bool,
+
+    /// Checks the answers against the expected answers file
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Expected answers file used by `--verify`
+    #[arg(long, default_value = ANSWERS)]
+    pub answers: PathBuf,
+
+    /// Runs the alternative implementation with this name, for the parts that have one
+    #[arg(long, value_name = "NAME")]
+    pub implementation: Option<String>,
+
+    /// Number of times each day is run to compute its timing statistics
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    repeat: u32,
+
+    /// Runs each day repeatedly until this time budget is spent, e.g. `500ms` or `2s`
+    #[arg(long, value_parser = parse_duration, conflicts_with = "repeat")]
+    budget: Option<Duration>,
+
+    /// Output format of the results
+    #[arg(long, value_enum, default_value_t)]
+    pub format: OutputFormat,
+
+    /// Reads file inputs in place through a memory map instead of copying them; an input must
+    /// then not be modified or truncated until the command ends
+    #[arg(long)]
+    pub mmap: bool,
+
+    /// Number of days run concurrently, defaults to the number of CPUs; use 1 for steadier timings
+    #[arg(long, short, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+}
+
+impl RunArgs {
+    pub fn selection(&self) -> DaySelection {
+        self.days.clone().unwrap_or(DaySelection::ALL)
+    }
+
+    pub fn jobs(&self) -> usize {
+        self.jobs.map_or_else(
+            || thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            |jobs| jobs as usize,
+        )
+    }
+
+    pub fn repetition(&self) -> Repetition {
+        match self.budget {
+            Some(budget) => Repetition::Budget(budget),
+            None => Repetition::Count(self.repeat as usize),
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct FetchArgs {
+    /// Day or range of days to fetch, e.g. `5` or `1..=8`
+    pub days: DaySelection,
+
+    #[command(flatten)]
+    pub client: ClientArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct SubmitArgs {
+    /// Day to submit an answer for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Part to submit an answer for, `1` or `2`
+    #[arg(value_parser = parse_part)]
+    pub part: Part,
+
+    /// Expected answers file the answer is recorded in once accepted
+    #[arg(long, default_value = ANSWERS)]
+    pub answers: PathBuf,
+
+    #[command(flatten)]
+    pub client: ClientArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct NewArgs {
+    /// Day to generate the crate of
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Root of the workspace the crate is generated in
+    #[arg(long, default_value = env!("CARGO_MANIFEST_DIR"))]
+    pub root: PathBuf,
+}
+
+#[derive(Debug, Args)]
+pub struct WatchArgs {
+    /// Day to watch
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Runs the tests of the day instead of the day itself
+    #[arg(long)]
+    pub tests: bool,
+
+    /// Builds in release mode
+    #[arg(long)]
+    pub release: bool,
+
+    /// Expected answers file the answers are verified against
+    #[arg(long, default_value = ANSWERS)]
+    pub answers: PathBuf,
+
+    /// Root of the workspace the day is built in
+    #[arg(long, default_value = env!("CARGO_MANIFEST_DIR"))]
+    pub root: PathBuf,
+}
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    /// Day or range of days to benchmark, every day by default
+    days: Option<DaySelection>,
+
+    /// Number of timed runs of each day
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+    pub samples: u32,
+
+    /// Baseline file the results are compared against
+    #[arg(long, default_value = BASELINE)]
+    pub baseline: PathBuf,
+
+    /// Saves the results as the new baseline
+    #[arg(long)]
+    pub save_baseline: bool,
+
+    /// Relative change of the mean, in percent, below which no change is reported
+    #[arg(long, default_value_t = 2.0)]
+    pub threshold: f64,
+
+    /// Fails when any phase regressed against the baseline
+    #[arg(long)]
+    pub fail_on_regression: bool,
+
+    /// Reads file inputs in place through a memory map instead of copying them; an input must
+    /// then not be modified or truncated until the command ends
+    #[arg(long)]
+    pub mmap: bool,
+}
+
+impl BenchArgs {
+    pub fn selection(&self) -> DaySelection {
+        self.days.clone().unwrap_or(DaySelection::ALL)
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct CompareArgs {
+    /// Day whose implementations are compared
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Number of timed runs of each implementation
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+    pub samples: u32,
+}
+
+#[derive(Debug, Args)]
+pub struct GenerateArgs {
+    /// Day to generate an input of
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Size of the input, e.g. its number of lines or the side of its grid depending on the day
+    #[arg(long, default_value_t = 100)]
+    pub size: usize,
+
+    /// Seed of the input, a random one by default
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// File the input is written to instead of stdout
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+}
+
+fn parse_part(part: &str) -> Result<Part, String> {
+    match part {
+        "1" | "one" => Ok(Part::One),
+        "2" | "two" => Ok(Part::Two),
+        _ => Err(format!("Invalid part: {part}, expected 1 or 2")),
+    }
+}
+
+/// How to reach the website, shared by every command talking to it.
+#[derive(Debug, Args)]
+pub struct ClientArgs {
+    /// Base URL of the website, e.g. a local server for testing
+    #[arg(long)]
+    base_url: Option<String>,
+
+    /// User-Agent header sent with every request
+    #[arg(long)]
+    user_agent: Option<String>,
+
+    /// Minimum time between two requests, e.g. `500ms` or `2s`
+    #[arg(long, value_parser = parse_duration, default_value = "1s")]
+    throttle: Duration,
+}
+
+impl ClientArgs {
+    pub fn settings(&self) -> Result<ClientSettings, Box<dyn Error>> {
+        Ok(ClientSettings::resolve(
+            self.base_url.clone(),
+            self.user_agent.clone(),
+            self.throttle,
+            Config::load_default()?,
+        ))
+    }
+}
+
+/// The days requested on the command line, as an inclusive range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection {
+    days: RangeInclusive<u8>,
+}
+
+impl DaySelection {
+    pub const ALL: DaySelection = DaySelection { days: 1..=25 };
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.days.contains(&day)
+    }
+
+    pub fn iter(&self) -> RangeInclusive<u8> {
+        self.days.clone()
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = DaySelectionError;
+
+    fn from_str(selection: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| {
+            day.trim()
+                .parse::<u8>()
+                .map_err(|_| DaySelectionError::InvalidDay(day.to_string()))
+        };
+
+        let days = if let Some((start, end)) = selection.split_once("..=") {
+            parse_day(start)?..=parse_day(end)?
+        } else if let Some((start, end)) = selection.split_once("..") {
+            let end = parse_day(end)?
+                .checked_sub(1)
+                .ok_or_else(|| DaySelectionError::EmptyRange(selection.to_string()))?;
+            parse_day(start)?..=end
+        } else {
+            let day = parse_day(selection)?;
+            day..=day
+        };
+
+        if days.is_empty() {
+            return Err(DaySelectionError::EmptyRange(selection.to_string()));
+        }
+
+        Ok(DaySelection { days })
+    }
+}
+
+#[derive(Debug)]
+pub enum DaySelectionError {
+    InvalidDay(String),
+    EmptyRange(String),
+}
+
+impl Display for DaySelectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DaySelectionError::InvalidDay(day) => write!(f, "Invalid day: {day}"),
+            DaySelectionError::EmptyRange(range) => write!(f, "Empty range of days: {range}"),
+        }
+    }
+}
+
+impl Error for DaySelectionError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_day_selection() {
+        assert_eq!(
+            Some(5..=5),
+            "5".parse::<DaySelection>().ok().map(|s| s.days)
+        );
+        assert_eq!(
+            Some(1..=8),
+            "1..=8".parse::<DaySelection>().ok().map(|s| s.days)
+        );
+        assert_eq!(
+            Some(3..=5),
+            "3..6".parse::<DaySelection>().ok().map(|s| s.days)
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_day_selection() {
+        assert!("five".parse::<DaySelection>().is_err());
+        assert!("6..=3".parse::<DaySelection>().is_err());
+        assert!("3..3".parse::<DaySelection>().is_err());
+        assert!("0..0".parse::<DaySelection>().is_err());
+    }
+}
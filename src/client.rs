@@ -0,0 +1,195 @@
+use crate::config::Config;
+use solution::answer::Answer;
+use solution::Part;
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The year every request is made for.
+pub const YEAR: u16 = 2024;
+/// Environment variable holding the session token, taking precedence over the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding the base URL of the website.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_USER_AGENT: &str =
+    "github.com/Fendrul/advent-of-code-2024 (advent-of-code-2024 runner)";
+
+/// Everything needed to talk to the website, resolved from the command line, the environment
+/// and the config file, in that order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientSettings {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub user_agent: String,
+    pub throttle: Duration,
+}
+
+impl ClientSettings {
+    pub fn resolve(
+        base_url: Option<String>,
+        user_agent: Option<String>,
+        throttle: Duration,
+        config: Config,
+    ) -> ClientSettings {
+        ClientSettings {
+            base_url: base_url
+                .or_else(|| env::var(BASE_URL_VAR).ok())
+                .or(config.base_url)
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            session: env::var(SESSION_VAR)
+                .ok()
+                .filter(|session| !session.is_empty())
+                .or(config.session),
+            user_agent: user_agent
+                .or(config.user_agent)
+                .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string()),
+            throttle,
+        }
+    }
+}
+
+/// A blocking client for the Advent of Code website, authenticated with a session token.
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    throttle: Throttle,
+}
+
+impl AocClient {
+    pub fn new(settings: &ClientSettings) -> Result<AocClient, ClientError> {
+        let session = settings
+            .session
+            .clone()
+            .ok_or(ClientError::MissingSession)?;
+
+        Ok(AocClient {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(&settings.user_agent)
+                .build(),
+            base_url: settings.base_url.trim_end_matches('/').to_string(),
+            session,
+            throttle: Throttle::new(settings.throttle),
+        })
+    }
+
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&mut self, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+
+        self.throttle.wait();
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(ClientError::from)?;
+
+        read_body(response)
+    }
+
+    /// Submits `answer` for `part` of `day` and returns the HTML page the website answered with.
+    pub fn submit(&mut self, day: u8, part: Part, answer: &Answer) -> Result<String, ClientError> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+
+        self.throttle.wait();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[
+                ("level", &part.number().to_string()),
+                ("answer", &answer.to_string()),
+            ])
+            .map_err(ClientError::from)?;
+
+        read_body(response)
+    }
+}
+
+fn read_body(response: ureq::Response) -> Result<String, ClientError> {
+    response
+        .into_string()
+        .map_err(|err| ClientError::Transport(err.to_string()))
+}
+
+/// Spaces requests so that two of them are never closer than `interval`.
+struct Throttle {
+    interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Throttle {
+    fn new(interval: Duration) -> Throttle {
+        Throttle {
+            interval,
+            last_request: None,
+        }
+    }
+
+    /// Sleeps until the next request is allowed, then records it as made.
+    fn wait(&mut self) {
+        if let Some(last_request) = self.last_request {
+            if let Some(remaining) = self.interval.checked_sub(last_request.elapsed()) {
+                thread::sleep(remaining);
+            }
+        }
+
+        self.last_request = Some(Instant::now());
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Status(u16, String),
+    Transport(String),
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(code, response) => {
+                ClientError::Status(code, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        }
+    }
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "No session token, set {SESSION_VAR} or `session` in the config file"
+            ),
+            ClientError::Status(code, body) => {
+                write!(f, "The server answered with status {code}: {}", body.trim())
+            }
+            ClientError::Transport(message) => write!(f, "Request failed: {message}"),
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_throttle_spaces_requests() {
+        let mut throttle = Throttle::new(Duration::from_millis(50));
+        let start = Instant::now();
+
+        throttle.wait();
+        assert!(start.elapsed() < Duration::from_millis(50));
+
+        throttle.wait();
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+}
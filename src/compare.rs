@@ -0,0 +1,157 @@
+use crate::cli::CompareArgs;
+use crate::days;
+use crate::input::InputResolver;
+use crate::timing::{Micros, Statistics};
+use solution::answer::Answer;
+use solution::registry::Solver;
+use solution::Part;
+use std::any::Any;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Instant;
+
+/// Runs every implementation of each part of a day on its input, printing their answers and
+/// timings side by side.
+///
+/// The comparison fails if the implementations of a part don't agree on its answer.
+pub fn compare(args: &CompareArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let day = days::all()
+        .into_iter()
+        .find(|day| day.number == args.day)
+        .ok_or(NothingToCompare(args.day))?;
+    let input = InputResolver::from_env(None).resolve(day.number, Path::new(day.input))?;
+    let parsed = (day.parse)(input.source()?.reader()?)?;
+    let mut agree = true;
+
+    for part in Part::ALL {
+        let timings = day.solvers[part.index()]
+            .iter()
+            .map(|solver| time_solver(solver, parsed.as_ref(), args.samples as usize))
+            .collect::<Vec<_>>();
+
+        agree &= timings
+            .windows(2)
+            .all(|pair| pair[0].answer == pair[1].answer);
+        println!("Day {} {part}", day.number);
+        println!("{}", render_timings(&timings));
+    }
+
+    Ok(if agree {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// The answer of one implementation of a part, with the time it takes to compute it.
+#[derive(Debug, Clone, PartialEq)]
+struct Timing {
+    name: &'static str,
+    answer: Answer,
+    statistics: Statistics,
+}
+
+fn time_solver(solver: &Solver, input: &dyn Any, samples: usize) -> Timing {
+    let mut answer = None;
+    let durations = (0..samples.max(1))
+        .map(|_| {
+            let timer = Instant::now();
+            answer = Some((solver.solve)(input));
+            timer.elapsed()
+        })
+        .collect::<Vec<_>>();
+
+    Timing {
+        name: solver.name,
+        answer: answer.expect("A solver is always run at least once"),
+        statistics: Statistics::from_samples(&durations)
+            .expect("A solver is always run at least once"),
+    }
+}
+
+/// Renders one line per implementation, comparing each to the first one, and flags answers
+/// differing from it.
+fn render_timings(timings: &[Timing]) -> String {
+    let name_width = timings
+        .iter()
+        .map(|timing| timing.name.len())
+        .max()
+        .unwrap_or_default();
+    let reference = timings.first();
+
+    timings
+        .iter()
+        .map(|timing| {
+            let mut line = format!(
+                "  {:<name_width$}  {} | median {}",
+                timing.name,
+                timing.answer,
+                Micros(timing.statistics.median)
+            );
+
+            if let Some(reference) = reference.filter(|reference| reference.name != timing.name) {
+                let speedup = reference.statistics.median.as_secs_f64()
+                    / timing.statistics.median.as_secs_f64().max(f64::EPSILON);
+                line.push_str(&format!(" ({speedup:.1}x vs {})", reference.name));
+
+                if reference.answer != timing.answer {
+                    line.push_str(&format!(" | differs from {}", reference.answer));
+                }
+            }
+
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[derive(Debug)]
+pub struct NothingToCompare(u8);
+
+impl Display for NothingToCompare {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {} has no registered solution", self.0)
+    }
+}
+
+impl Error for NothingToCompare {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    fn timing(name: &'static str, answer: u64, median_micros: u64) -> Timing {
+        let median = Duration::from_micros(median_micros);
+
+        Timing {
+            name,
+            answer: Answer::from(answer),
+            statistics: Statistics {
+                runs: 1,
+                min: median,
+                median,
+                mean: median,
+                stddev: Duration::ZERO,
+            },
+        }
+    }
+
+    #[test]
+    fn test_render_timings() {
+        let timings = [
+            timing("default", 11387, 1000),
+            timing("pruned", 11387, 250),
+            timing("wrong", 42, 500),
+        ];
+
+        assert_eq!(
+            "  default  11387 | median 1000.0µs\n\
+             \x20 pruned   11387 | median 250.0µs (4.0x vs default)\n\
+             \x20 wrong    42 | median 500.0µs (2.0x vs default) | differs from 11387",
+            render_timings(&timings)
+        );
+    }
+}
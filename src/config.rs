@@ -0,0 +1,96 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use toml_edit::DocumentMut;
+
+/// Environment variable overriding the location of the configuration file.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Settings read from the configuration file, all of them optional.
+///
+/// The file is a flat TOML document, for example:
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// user-agent = "github.com/someone/advent-of-code-2024 by someone@example.com"
+/// base-url = "https://adventofcode.com"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub user_agent: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// Loads the configuration file at `path`, treating a missing file as an empty one.
+    pub fn load(path: &Path) -> Result<Config, Box<dyn Error>> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Config::default()),
+            Err(err) => return Err(Box::new(err)),
+        };
+        let document = content.parse::<DocumentMut>()?;
+        let string = |key: &str| {
+            document
+                .get(key)
+                .and_then(|item| item.as_str())
+                .map(str::to_string)
+        };
+
+        Ok(Config {
+            session: string("session"),
+            user_agent: string("user-agent"),
+            base_url: string("base-url"),
+        })
+    }
+
+    /// Loads the configuration file from `$AOC_CONFIG`, or from `advent-of-code-2024/config.toml`
+    /// under `$XDG_CONFIG_HOME` or `$HOME/.config`.
+    pub fn load_default() -> Result<Config, Box<dyn Error>> {
+        match config_path() {
+            Some(path) => Config::load(&path),
+            None => Ok(Config::default()),
+        }
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os(CONFIG_VAR) {
+        return Some(PathBuf::from(path));
+    }
+
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("advent-of-code-2024").join("config.toml"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_load_config() -> Result<(), Box<dyn Error>> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("config.toml");
+        fs::write(
+            &path,
+            "session = \"abc\"\nbase-url = \"http://localhost\"\n",
+        )?;
+
+        let expected = Config {
+            session: Some("abc".to_string()),
+            user_agent: None,
+            base_url: Some("http://localhost".to_string()),
+        };
+        assert_eq!(expected, Config::load(&path)?);
+        assert_eq!(
+            Config::default(),
+            Config::load(&dir.path().join("missing.toml"))?
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use crate::allocation::{self, AllocationStats};
+use file_reader::file_reader::FileReader;
+use solution::answer::Answer;
+use solution::registry::{self, ParseFn, Solver};
+use solution::Part;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+// The crates of the days only need to be linked in, their solutions registering themselves. The
+// build script links every `day-<N>` dependency of the runner.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+/// A registered day of the calendar, with the input it runs on by default.
+pub struct Day {
+    pub number: u8,
+    pub input: &'static str,
+    pub parse: ParseFn,
+    /// The solvers of each part, the default one first.
+    pub solvers: [Vec<&'static Solver>; 2],
+}
+
+/// Every day registered by the linked crates, sorted by number.
+pub fn all() -> Vec<Day> {
+    registry::parsers()
+        .into_iter()
+        .map(|parser| Day {
+            number: parser.day,
+            input: parser.input,
+            parse: parser.parse,
+            solvers: Part::ALL.map(|part| registry::solvers(parser.day, part)),
+        })
+        .collect()
+}
+
+impl Day {
+    /// The solvers of both parts run for the `implementation`, when one is selected.
+    ///
+    /// A part without a solver of that name runs its default solver, but at least one part must
+    /// have one.
+    pub fn select(
+        &self,
+        implementation: Option<&str>,
+    ) -> Result<[&'static Solver; 2], Box<dyn Error>> {
+        let find = |part: Part, name: &str| {
+            self.solvers[part.index()]
+                .iter()
+                .find(|solver| solver.name == name)
+                .copied()
+        };
+
+        if let Some(name) = implementation {
+            if Part::ALL.iter().all(|part| find(*part, name).is_none()) {
+                return Err(Box::new(UnknownImplementation {
+                    day: self.number,
+                    name: name.to_string(),
+                }));
+            }
+        }
+
+        let select = |part: Part| {
+            implementation
+                .and_then(|name| find(part, name))
+                .or_else(|| self.solvers[part.index()].first().copied())
+                .ok_or(MissingSolver {
+                    day: self.number,
+                    part,
+                })
+        };
+
+        Ok([select(Part::One)?, select(Part::Two)?])
+    }
+
+    /// Parses the input read by `reader` then solves both parts with the `solvers`, timing each
+    /// phase.
+    pub fn run(&self, reader: FileReader, solvers: [&Solver; 2]) -> Result<DayRun, Box<dyn Error>> {
+        let ((input, parse_time), parse_allocations) = allocation::measure(|| {
+            let timer = Instant::now();
+            let input = (self.parse)(reader);
+            (input, timer.elapsed())
+        });
+        let input = input?;
+
+        let ((part_one, part_one_time), part_one_allocations) = allocation::measure(|| {
+            let timer = Instant::now();
+            let part_one = (solvers[0].solve)(input.as_ref());
+            (part_one, timer.elapsed())
+        });
+
+        let ((part_two, part_two_time), part_two_allocations) = allocation::measure(|| {
+            let timer = Instant::now();
+            let part_two = (solvers[1].solve)(input.as_ref());
+            (part_two, timer.elapsed())
+        });
+
+        Ok(DayRun {
+            part_one,
+            part_two,
+            parse_time,
+            part_one_time,
+            part_two_time,
+            allocations: parse_allocations
+                .zip(part_one_allocations)
+                .zip(part_two_allocations)
+                .map(|((parse, part_one), part_two)| [parse, part_one, part_two]),
+        })
+    }
+}
+
+/// The answers of one run of a day, with the time spent in each of its phases.
+pub struct DayRun {
+    pub part_one: Answer,
+    pub part_two: Answer,
+    pub parse_time: Duration,
+    pub part_one_time: Duration,
+    pub part_two_time: Duration,
+    /// The allocations of the parsing and of each part, with the `alloc-stats` feature.
+    pub allocations: Option<[AllocationStats; 3]>,
+}
+
+impl DayRun {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.part_one_time + self.part_two_time
+    }
+}
+
+#[derive(Debug)]
+pub struct UnknownImplementation {
+    day: u8,
+    name: String,
+}
+
+impl Display for UnknownImplementation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {} has no implementation named {}",
+            self.day, self.name
+        )
+    }
+}
+
+impl Error for UnknownImplementation {}
+
+#[derive(Debug)]
+pub struct MissingSolver {
+    day: u8,
+    part: Part,
+}
+
+impl Display for MissingSolver {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of day {} has no registered solver",
+            self.part, self.day
+        )
+    }
+}
+
+impl Error for MissingSolver {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use solution::registry::DEFAULT_NAME;
+
+    #[test]
+    fn test_every_day_is_registered() {
+        let days = all();
+
+        assert_eq!(
+            (1..=8).collect::<Vec<_>>(),
+            days.iter().map(|day| day.number).collect::<Vec<_>>()
+        );
+        assert!(days
+            .iter()
+            .flat_map(|day| &day.solvers)
+            .all(|solvers| solvers
+                .first()
+                .is_some_and(|solver| solver.name == DEFAULT_NAME)));
+    }
+
+    #[test]
+    fn test_select_implementation() {
+        let day = all().into_iter().find(|day| day.number == 7).unwrap();
+
+        let [part_one, part_two] = day.select(Some("pruned")).unwrap();
+        assert_eq!((DEFAULT_NAME, "pruned"), (part_one.name, part_two.name));
+
+        let [part_one, part_two] = day.select(None).unwrap();
+        assert_eq!((DEFAULT_NAME, DEFAULT_NAME), (part_one.name, part_two.name));
+
+        assert_eq!(
+            "Day 7 has no implementation named fastest",
+            day.select(Some("fastest")).unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use crate::cli::FetchArgs;
+use crate::client::{AocClient, ClientSettings};
+use crate::input::{cache_dir, day_file_name};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Downloads the input of every selected day into the cache directory, skipping the ones
+/// already cached.
+pub fn fetch(args: &FetchArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let cache_dir = cache_dir().ok_or(NoCacheDir)?;
+    let mut fetcher = Fetcher::new(args.client.settings()?, cache_dir);
+
+    for day in args.days.iter() {
+        match fetcher.fetch(day)? {
+            Fetched::Cached(path) => println!("Day {day}: already cached at {}", path.display()),
+            Fetched::Downloaded(path) => println!("Day {day}: downloaded to {}", path.display()),
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Fetches inputs into a cache directory, only connecting once an input is actually missing.
+pub struct Fetcher {
+    settings: ClientSettings,
+    cache_dir: PathBuf,
+    client: Option<AocClient>,
+}
+
+impl Fetcher {
+    pub fn new(settings: ClientSettings, cache_dir: PathBuf) -> Fetcher {
+        Fetcher {
+            settings,
+            cache_dir,
+            client: None,
+        }
+    }
+
+    pub fn fetch(&mut self, day: u8) -> Result<Fetched, Box<dyn Error>> {
+        let path = self.cache_dir.join(day_file_name(day));
+
+        if path.is_file() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let client = match &mut self.client {
+            Some(client) => client,
+            None => self.client.insert(AocClient::new(&self.settings)?),
+        };
+        let input = client.input(day)?;
+
+        write_atomically(&path, &input)?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+/// Writes through a temporary file so that an interrupted download never leaves a partial input
+/// in the cache.
+fn write_atomically(path: &Path, content: &str) -> Result<(), Box<dyn Error>> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let partial_path = path.with_extension("part");
+    fs::write(&partial_path, content)?;
+    fs::rename(&partial_path, path)?;
+
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct NoCacheDir;
+
+impl Display for NoCacheDir {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "No cache directory, set AOC_CACHE_DIR, XDG_CACHE_HOME or HOME"
+        )
+    }
+}
+
+impl Error for NoCacheDir {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::stub_server::StubServer;
+    use std::time::Duration;
+
+    fn settings(base_url: String) -> ClientSettings {
+        ClientSettings {
+            base_url,
+            session: Some("secret".to_string()),
+            user_agent: "aoc-tests".to_string(),
+            throttle: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_fetch_downloads_then_uses_cache() -> Result<(), Box<dyn Error>> {
+        let server = StubServer::start(|request| (200, format!("input of {}", request.url)));
+        let dir = tempfile::tempdir()?;
+        let mut fetcher = Fetcher::new(settings(server.url()), dir.path().to_path_buf());
+        let path = dir.path().join("day-5");
+
+        assert_eq!(Fetched::Downloaded(path.clone()), fetcher.fetch(5)?);
+        assert_eq!("input of /2024/day/5/input", fs::read_to_string(&path)?);
+        assert_eq!(Fetched::Cached(path), fetcher.fetch(5)?);
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!("GET", requests[0].method);
+        assert_eq!(Some("session=secret"), requests[0].header("Cookie"));
+        assert_eq!(Some("aoc-tests"), requests[0].header("User-Agent"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_failure_caches_nothing() -> Result<(), Box<dyn Error>> {
+        let server = StubServer::start(|_| (404, "Not found".to_string()));
+        let dir = tempfile::tempdir()?;
+        let mut fetcher = Fetcher::new(settings(server.url()), dir.path().to_path_buf());
+
+        assert!(fetcher.fetch(25).is_err());
+        assert!(fs::read_dir(dir.path())?.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_without_session() -> Result<(), Box<dyn Error>> {
+        let dir = tempfile::tempdir()?;
+        let settings = ClientSettings {
+            session: None,
+            ..settings("http://127.0.0.1:9".to_string())
+        };
+
+        let error = Fetcher::new(settings, dir.path().to_path_buf())
+            .fetch(1)
+            .expect_err("No session token is configured");
+        assert!(error.to_string().contains("session"));
+
+        Ok(())
+    }
+}
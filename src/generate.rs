@@ -0,0 +1,45 @@
+use crate::cli::GenerateArgs;
+use solution::generator;
+use solution::registry;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::process::ExitCode;
+
+/// Generates a random input of a day, printed or written to a file.
+///
+/// The seed is always reported, so that an input which makes a solution fail can be generated
+/// again.
+pub fn generate(args: &GenerateArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let generator = registry::generator(args.day).ok_or(NoGenerator(args.day))?;
+    let seed = args.seed.unwrap_or_else(rand::random);
+    let input = (generator.generate)(&mut generator::rng(seed), args.size);
+
+    match &args.output {
+        Some(path) => {
+            fs::write(path, input)?;
+            println!(
+                "Generated day {} with seed {seed} into {}",
+                args.day,
+                path.display()
+            );
+        }
+        None => {
+            eprintln!("Generated day {} with seed {seed}", args.day);
+            print!("{input}");
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+#[derive(Debug)]
+pub struct NoGenerator(u8);
+
+impl Display for NoGenerator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {} has no input generator", self.0)
+    }
+}
+
+impl Error for NoGenerator {}
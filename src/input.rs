@@ -0,0 +1,348 @@
+//! Locates the puzzle input of a day.
+//!
+//! The input is looked up in a fixed order, the first existing location winning:
+//!
+//! 1. the path given with `--input`, or stdin when that path is `-`;
+//! 2. `$AOC_INPUT_DIR/day-<N>`;
+//! 3. the `src/input` file of the day crate;
+//! 4. `<cache>/day-<N>`, where `<cache>` is `$AOC_CACHE_DIR`, or `advent-of-code-2024` under
+//!    `$XDG_CACHE_HOME` or `$HOME/.cache`.
+
+use file_reader::file_reader::FileReader;
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Environment variable holding a directory of inputs named `day-<N>`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+/// Environment variable overriding the shared cache directory.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+/// Value of `--input` that reads the input from stdin.
+pub const STDIN: &str = "-";
+
+/// The name answers are stored under for inputs found automatically.
+pub const DEFAULT_INPUT_NAME: &str = "input";
+
+/// Where the input of a day was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputLocation {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputLocation {
+    /// Whether the input is a file of an `examples` directory, which holds an example along with
+    /// its expected answers.
+    pub fn is_example(&self) -> bool {
+        match self {
+            InputLocation::Stdin => false,
+            InputLocation::File(path) => path
+                .parent()
+                .is_some_and(|dir| dir.file_name().is_some_and(|name| name == "examples")),
+        }
+    }
+}
+
+impl Display for InputLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputLocation::Stdin => write!(f, "stdin"),
+            InputLocation::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// A located input, along with the name its answers are stored under.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedInput {
+    pub name: String,
+    pub location: InputLocation,
+    mmap: bool,
+}
+
+impl ResolvedInput {
+    /// The input, ready to be read as many times as needed: it is read whole once, unless the
+    /// resolver was asked to map files in memory on each read instead.
+    pub fn source(&self) -> Result<InputSource, Box<dyn Error>> {
+        Ok(match &self.location {
+            InputLocation::Stdin => InputSource::Text(self.read()?),
+            InputLocation::File(path) if self.mmap => InputSource::Mapped(path.clone()),
+            InputLocation::File(path) => InputSource::File {
+                path: path.clone(),
+                content: self.read()?,
+            },
+        })
+    }
+
+    /// Reads the whole input.
+    pub fn read(&self) -> Result<String, Box<dyn Error>> {
+        let mut content = String::new();
+
+        match &self.location {
+            InputLocation::Stdin => {
+                std::io::stdin().read_to_string(&mut content)?;
+            }
+            InputLocation::File(path) => {
+                content = std::fs::read_to_string(path)
+                    .map_err(|error| format!("Couldn't read {}: {error}", path.display()))?;
+            }
+        }
+
+        Ok(content)
+    }
+}
+
+/// The content of an input, from which a reader is made for each run of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Content held in memory, such as stdin or the input of an example.
+    Text(String),
+    /// The content of a file, read whole.
+    File { path: PathBuf, content: String },
+    /// A file, memory-mapped rather than copied so that large inputs are read in place.
+    Mapped(PathBuf),
+}
+
+impl InputSource {
+    /// A reader over the whole input, naming its file in parse errors.
+    pub fn reader(&self) -> Result<FileReader, Box<dyn Error>> {
+        Ok(match self {
+            InputSource::Text(content) => FileReader::from_string(content.clone()),
+            InputSource::File { path, content } => {
+                FileReader::from_string(content.clone()).with_path(path)
+            }
+            // SAFETY: mapping is only done when asked for with `--mmap`, whose documentation
+            // requires the input to be left untouched until the command ends.
+            InputSource::Mapped(path) => unsafe { FileReader::mapped(path) }
+                .map_err(|error| format!("Couldn't read {}: {error}", path.display()))?,
+        })
+    }
+}
+
+/// Finds the input of each day, following the precedence described in the module documentation.
+#[derive(Debug, Clone, Default)]
+pub struct InputResolver {
+    explicit: Option<InputLocation>,
+    input_dir: Option<PathBuf>,
+    cache_dir: Option<PathBuf>,
+    mmap: bool,
+}
+
+impl InputResolver {
+    /// Builds a resolver from the `--input` argument and the environment.
+    pub fn from_env(explicit: Option<&Path>) -> InputResolver {
+        InputResolver {
+            explicit: explicit.map(|path| {
+                if path == Path::new(STDIN) {
+                    InputLocation::Stdin
+                } else {
+                    InputLocation::File(path.to_path_buf())
+                }
+            }),
+            input_dir: env::var_os(INPUT_DIR_VAR).map(PathBuf::from),
+            cache_dir: cache_dir(),
+            mmap: false,
+        }
+    }
+
+    /// Maps the files found in memory instead of reading them whole, which they must then not be
+    /// modified until they are no longer read.
+    #[must_use]
+    pub fn with_mmap(mut self, mmap: bool) -> InputResolver {
+        self.mmap = mmap;
+        self
+    }
+
+    /// Finds the input of `day`, whose crate ships its input at `default_input`.
+    pub fn resolve(&self, day: u8, default_input: &Path) -> Result<ResolvedInput, InputNotFound> {
+        match &self.explicit {
+            Some(InputLocation::Stdin) => {
+                return Ok(ResolvedInput {
+                    name: "stdin".to_string(),
+                    location: InputLocation::Stdin,
+                    mmap: self.mmap,
+                })
+            }
+            Some(InputLocation::File(path)) => {
+                return if path.is_file() {
+                    Ok(ResolvedInput {
+                        name: file_name(path),
+                        location: InputLocation::File(path.clone()),
+                        mmap: self.mmap,
+                    })
+                } else {
+                    Err(InputNotFound {
+                        day,
+                        tried: vec![path.clone()],
+                    })
+                };
+            }
+            None => {}
+        }
+
+        let day_file = day_file_name(day);
+        let candidates = [
+            self.input_dir.as_ref().map(|dir| dir.join(&day_file)),
+            Some(default_input.to_path_buf()),
+            self.cache_dir.as_ref().map(|dir| dir.join(&day_file)),
+        ];
+        let tried = candidates.into_iter().flatten().collect::<Vec<_>>();
+
+        match tried.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(ResolvedInput {
+                name: DEFAULT_INPUT_NAME.to_string(),
+                location: InputLocation::File(path.clone()),
+                mmap: self.mmap,
+            }),
+            None => Err(InputNotFound { day, tried }),
+        }
+    }
+}
+
+/// The name of the input file of `day` in the input and cache directories.
+pub fn day_file_name(day: u8) -> String {
+    format!("day-{day}")
+}
+
+/// The shared cache directory, if one can be determined from the environment.
+pub fn cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os(CACHE_DIR_VAR) {
+        return Some(PathBuf::from(dir));
+    }
+
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .map(|dir| dir.join("advent-of-code-2024"))
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.to_string_lossy().into_owned(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+#[derive(Debug)]
+pub struct InputNotFound {
+    day: u8,
+    tried: Vec<PathBuf>,
+}
+
+impl Display for InputNotFound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No input found for day {}, tried:", self.day)?;
+
+        for path in &self.tried {
+            write!(f, "\n  - {}", path.display())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for InputNotFound {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    fn resolver(dir: &Path) -> InputResolver {
+        InputResolver {
+            explicit: None,
+            input_dir: Some(dir.join("inputs")),
+            cache_dir: Some(dir.join("cache")),
+            mmap: false,
+        }
+    }
+
+    #[test]
+    fn test_resolve_precedence() -> Result<(), Box<dyn Error>> {
+        let dir = tempfile::tempdir()?;
+        let default_input = dir.path().join("default");
+        fs::create_dir_all(dir.path().join("inputs"))?;
+        fs::create_dir_all(dir.path().join("cache"))?;
+
+        let resolver = resolver(dir.path());
+        let location = |day| {
+            resolver
+                .resolve(day, &default_input)
+                .map(|input| input.location)
+        };
+
+        fs::write(dir.path().join("cache/day-3"), "cache")?;
+        assert_eq!(
+            InputLocation::File(dir.path().join("cache/day-3")),
+            location(3)?
+        );
+
+        fs::write(&default_input, "default")?;
+        assert_eq!(InputLocation::File(default_input.clone()), location(3)?);
+
+        fs::write(dir.path().join("inputs/day-3"), "inputs")?;
+        assert_eq!(
+            InputLocation::File(dir.path().join("inputs/day-3")),
+            location(3)?
+        );
+
+        let explicit = dir.path().join("explicit.txt");
+        fs::write(&explicit, "explicit")?;
+        let resolved = InputResolver {
+            explicit: Some(InputLocation::File(explicit.clone())),
+            ..resolver.clone()
+        }
+        .resolve(3, &default_input)?;
+        assert_eq!("explicit.txt", resolved.name);
+        assert_eq!("explicit", resolved.read()?);
+        assert_eq!(
+            InputSource::File {
+                path: explicit.clone(),
+                content: "explicit".to_string()
+            },
+            resolved.source()?
+        );
+        assert_eq!(
+            vec!["explicit".to_string()],
+            resolved.source()?.reader()?.collect::<Vec<_>>()
+        );
+
+        let mapped = InputResolver {
+            explicit: Some(InputLocation::File(explicit.clone())),
+            ..resolver.clone()
+        }
+        .with_mmap(true)
+        .resolve(3, &default_input)?;
+        assert_eq!(InputSource::Mapped(explicit), mapped.source()?);
+        assert_eq!(
+            vec!["explicit".to_string()],
+            mapped.source()?.reader()?.collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_input_lists_tried_locations() -> Result<(), Box<dyn Error>> {
+        let dir = tempfile::tempdir()?;
+        let default_input = dir.path().join("default");
+
+        let error = resolver(dir.path())
+            .resolve(4, &default_input)
+            .expect_err("No input exists");
+        let message = error.to_string();
+
+        assert!(message.starts_with("No input found for day 4"));
+        for path in [
+            dir.path().join("inputs/day-4"),
+            default_input,
+            dir.path().join("cache/day-4"),
+        ] {
+            assert!(message.contains(&path.display().to_string()));
+        }
+
+        Ok(())
+    }
+}
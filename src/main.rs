@@ -1,3 +1,44 @@
-fn main() {
-    println!("Hello, world!");
+mod allocation;
+mod answers;
+mod bench;
+mod cli;
+mod client;
+mod compare;
+mod config;
+mod days;
+mod fetch;
+mod generate;
+mod input;
+mod output;
+mod runner;
+mod scaffold;
+#[cfg(test)]
+mod stub_server;
+mod submit;
+mod timing;
+mod watch;
+
+use clap::Parser;
+use cli::{Cli, Command};
+use std::error::Error;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result: Result<ExitCode, Box<dyn Error>> = match cli.command {
+        Command::Run(args) => runner::run(&args),
+        Command::Fetch(args) => fetch::fetch(&args),
+        Command::Submit(args) => submit::submit(&args),
+        Command::New(args) => scaffold::new_day(&args),
+        Command::Watch(args) => watch::watch(&args),
+        Command::Bench(args) => bench::bench(&args),
+        Command::Compare(args) => compare::compare(&args),
+        Command::Generate(args) => generate::generate(&args),
+    };
+
+    result.unwrap_or_else(|error| {
+        eprintln!("Error: {error}");
+        ExitCode::FAILURE
+    })
 }
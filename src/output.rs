@@ -0,0 +1,534 @@
+//! Rendering of the runner results as a human readable report or summary table, JSON or CSV.
+//!
+//! JSON and CSV share the same schema: one record per day and part, with the fields below in
+//! this order. The JSON document is an object holding the schema `version` and the `records`
+//! array; the CSV output is a header line followed by one line per record. Fields are only ever
+//! added at the end, and any other change bumps [`SCHEMA_VERSION`].
+//!
+//! | Field             | Type            | Description                                                   |
+//! |-------------------|-----------------|---------------------------------------------------------------|
+//! | `day`             | integer         | Day of the calendar                                           |
+//! | `part`            | integer         | Part of the puzzle, `1` or `2`                                |
+//! | `input`           | string          | Name of the input, as used in the expected answers file       |
+//! | `answer`          | string          | Computed answer                                               |
+//! | `status`          | string          | `correct`, `wrong`, `unknown`, `unchecked` without verify, or |
+//! |                   |                 | `failed` when the day couldn't be solved                      |
+//! | `expected`        | string or null  | Expected answer, when known                                   |
+//! | `runs`            | integer         | Number of runs the timings are computed over                  |
+//! | `parse_min_ns`    | integer         | Minimum parsing time of the day, in nanoseconds               |
+//! | `parse_median_ns` | integer         | Median parsing time of the day, in nanoseconds                |
+//! | `parse_mean_ns`   | integer         | Mean parsing time of the day, in nanoseconds                  |
+//! | `parse_stddev_ns` | integer         | Standard deviation of the parsing time, in nanoseconds        |
+//! | `min_ns`          | integer         | Minimum solving time of the part, in nanoseconds              |
+//! | `median_ns`       | integer         | Median solving time of the part, in nanoseconds               |
+//! | `mean_ns`         | integer         | Mean solving time of the part, in nanoseconds                 |
+//! | `stddev_ns`       | integer         | Standard deviation of the solving time, in nanoseconds        |
+//! | `error`           | string or null  | Why the day couldn't be solved, when it failed                |
+//! | `parse_allocations`     | integer or null | Allocations made by the parsing of the day              |
+//! | `parse_allocated_bytes` | integer or null | Bytes allocated by the parsing of the day               |
+//! | `parse_peak_live_bytes` | integer or null | Peak bytes live at once during the parsing of the day   |
+//! | `allocations`           | integer or null | Allocations made by the part                            |
+//! | `allocated_bytes`       | integer or null | Bytes allocated by the part                             |
+//! | `peak_live_bytes`       | integer or null | Peak bytes live at once during the part                 |
+//!
+//! A failed day has an empty `answer`, and zero `runs` and timings. Allocations are only counted
+//! when the runner is built with the `alloc-stats` feature, and are null otherwise. In CSV, a
+//! missing value is an empty field.
+
+use crate::allocation::AllocationStats;
+use crate::answers::Verification;
+use crate::runner::{DayFailure, DayOutcome, DayReport};
+use crate::timing::{Micros, Statistics};
+use clap::ValueEnum;
+use serde::Serialize;
+use solution::Part;
+use std::time::Duration;
+
+/// Version of the JSON and CSV schema.
+pub const SCHEMA_VERSION: u32 = 1;
+
+const CSV_HEADER: &str = "day,part,input,answer,status,expected,runs,\
+parse_min_ns,parse_median_ns,parse_mean_ns,parse_stddev_ns,min_ns,median_ns,mean_ns,stddev_ns,error,\
+parse_allocations,parse_allocated_bytes,parse_peak_live_bytes,allocations,allocated_bytes,peak_live_bytes";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    /// One line per day for its parsing and per part with the answer, its status and time, and the
+    /// total time
+    Summary,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Serialize)]
+struct Document {
+    version: u32,
+    records: Vec<Record>,
+}
+
+/// The result of one part of one day, as exported in JSON and CSV.
+#[derive(Debug, Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: String,
+    status: &'static str,
+    expected: Option<String>,
+    runs: usize,
+    parse_min_ns: u64,
+    parse_median_ns: u64,
+    parse_mean_ns: u64,
+    parse_stddev_ns: u64,
+    min_ns: u64,
+    median_ns: u64,
+    mean_ns: u64,
+    stddev_ns: u64,
+    error: Option<String>,
+    parse_allocations: Option<u64>,
+    parse_allocated_bytes: Option<u64>,
+    parse_peak_live_bytes: Option<u64>,
+    allocations: Option<u64>,
+    allocated_bytes: Option<u64>,
+    peak_live_bytes: Option<u64>,
+}
+
+impl Record {
+    fn from_report(report: &DayReport, part: Part) -> Record {
+        let index = part.index();
+        let answer = &report.answers[index];
+
+        let (status, expected) = match report.verifications.as_ref().map(|v| &v[index]) {
+            None => ("unchecked", None),
+            Some(Verification::Correct) => ("correct", Some(answer.to_string())),
+            Some(Verification::Wrong(expected)) => ("wrong", Some(expected.to_string())),
+            Some(Verification::Unknown) => ("unknown", None),
+        };
+
+        let parse = &report.parse;
+        let part_statistics = &report.parts[index];
+        let parse_allocations = report.allocations.map(|allocations| allocations[0]);
+        let part_allocations = report.allocations.map(|allocations| allocations[index + 1]);
+
+        Record {
+            day: report.day,
+            part: part.number(),
+            input: report.input_name.clone(),
+            answer: answer.to_string(),
+            status,
+            expected,
+            runs: part_statistics.runs,
+            parse_min_ns: nanos(parse.min),
+            parse_median_ns: nanos(parse.median),
+            parse_mean_ns: nanos(parse.mean),
+            parse_stddev_ns: nanos(parse.stddev),
+            min_ns: nanos(part_statistics.min),
+            median_ns: nanos(part_statistics.median),
+            mean_ns: nanos(part_statistics.mean),
+            stddev_ns: nanos(part_statistics.stddev),
+            error: None,
+            parse_allocations: parse_allocations.map(|stats| stats.count),
+            parse_allocated_bytes: parse_allocations.map(|stats| stats.bytes),
+            parse_peak_live_bytes: parse_allocations.map(|stats| stats.peak_live_bytes),
+            allocations: part_allocations.map(|stats| stats.count),
+            allocated_bytes: part_allocations.map(|stats| stats.bytes),
+            peak_live_bytes: part_allocations.map(|stats| stats.peak_live_bytes),
+        }
+    }
+
+    fn from_failure(failure: &DayFailure, part: Part) -> Record {
+        Record {
+            day: failure.day,
+            part: part.number(),
+            input: String::new(),
+            answer: String::new(),
+            status: "failed",
+            expected: None,
+            runs: 0,
+            parse_min_ns: 0,
+            parse_median_ns: 0,
+            parse_mean_ns: 0,
+            parse_stddev_ns: 0,
+            min_ns: 0,
+            median_ns: 0,
+            mean_ns: 0,
+            stddev_ns: 0,
+            error: Some(failure.message.clone()),
+            parse_allocations: None,
+            parse_allocated_bytes: None,
+            parse_peak_live_bytes: None,
+            allocations: None,
+            allocated_bytes: None,
+            peak_live_bytes: None,
+        }
+    }
+
+    fn to_csv_line(&self) -> String {
+        [
+            self.day.to_string(),
+            self.part.to_string(),
+            csv_field(&self.input),
+            csv_field(&self.answer),
+            self.status.to_string(),
+            self.expected.as_deref().map(csv_field).unwrap_or_default(),
+            self.runs.to_string(),
+            self.parse_min_ns.to_string(),
+            self.parse_median_ns.to_string(),
+            self.parse_mean_ns.to_string(),
+            self.parse_stddev_ns.to_string(),
+            self.min_ns.to_string(),
+            self.median_ns.to_string(),
+            self.mean_ns.to_string(),
+            self.stddev_ns.to_string(),
+            self.error.as_deref().map(csv_field).unwrap_or_default(),
+            optional_field(self.parse_allocations),
+            optional_field(self.parse_allocated_bytes),
+            optional_field(self.parse_peak_live_bytes),
+            optional_field(self.allocations),
+            optional_field(self.allocated_bytes),
+            optional_field(self.peak_live_bytes),
+        ]
+        .join(",")
+    }
+}
+
+/// Renders the outcomes of a run in the requested format.
+pub fn render(format: OutputFormat, outcomes: &[DayOutcome]) -> String {
+    match format {
+        OutputFormat::Table => render_table(outcomes),
+        OutputFormat::Summary => render_summary(outcomes),
+        OutputFormat::Json => {
+            let document = Document {
+                version: SCHEMA_VERSION,
+                records: records(outcomes),
+            };
+
+            serde_json::to_string_pretty(&document).expect("Records are always serializable")
+        }
+        OutputFormat::Csv => {
+            let mut lines = vec![CSV_HEADER.to_string()];
+            lines.extend(records(outcomes).iter().map(Record::to_csv_line));
+
+            lines.join("\n")
+        }
+    }
+}
+
+fn records(outcomes: &[DayOutcome]) -> Vec<Record> {
+    outcomes
+        .iter()
+        .flat_map(|outcome| {
+            Part::ALL.map(|part| match outcome {
+                Ok(report) => Record::from_report(report, part),
+                Err(failure) => Record::from_failure(failure, part),
+            })
+        })
+        .collect()
+}
+
+fn render_table(outcomes: &[DayOutcome]) -> String {
+    let mut lines = Vec::new();
+
+    for outcome in outcomes {
+        let report = match outcome {
+            Ok(report) => report,
+            Err(failure) => {
+                lines.push(format!("Day {} failed: {}", failure.day, failure.message));
+                continue;
+            }
+        };
+
+        let runs = report.total.runs;
+        let plural = if runs == 1 { "" } else { "s" };
+        lines.push(format!("Day {} ({runs} run{plural})", report.day));
+
+        for part in Part::ALL {
+            let index = part.index();
+            let answer = &report.answers[index];
+
+            match &report.verifications {
+                Some(verifications) => {
+                    lines.push(format!("  {part}: {answer} ({})", verifications[index]));
+                }
+                None => lines.push(format!("  {part}: {answer}")),
+            }
+        }
+
+        let phases: [(&str, &Statistics); 4] = [
+            ("Parse", &report.parse),
+            ("Part one", &report.parts[0]),
+            ("Part two", &report.parts[1]),
+            ("Total", &report.total),
+        ];
+
+        for (phase, statistics) in phases {
+            lines.push(format!("  {:<9} {statistics}", format!("{phase}:")));
+        }
+
+        if let Some(allocations) = &report.allocations {
+            lines.push("  Allocations:".to_string());
+
+            for (phase, stats) in ["Parse", "Part one", "Part two"].iter().zip(allocations) {
+                lines.push(format!(
+                    "    {:<9} {}",
+                    format!("{phase}:"),
+                    render_allocations(stats)
+                ));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Renders an aligned table with one row for the parsing and each part of every day, and the
+/// sum of their times on the last row.
+///
+/// The times are medians, so the sum differs from the median time of whole runs when days are
+/// run more than once.
+fn render_summary(outcomes: &[DayOutcome]) -> String {
+    let mut rows = vec![["Day", "Part", "Answer", "Status", "Time"].map(str::to_string)];
+    let mut total = Duration::ZERO;
+
+    for outcome in outcomes {
+        match outcome {
+            Ok(report) => {
+                total += report.parse.median;
+                rows.push([
+                    report.day.to_string(),
+                    "parse".to_string(),
+                    String::new(),
+                    String::new(),
+                    Micros(report.parse.median).to_string(),
+                ]);
+
+                for part in Part::ALL {
+                    let index = part.index();
+                    total += report.parts[index].median;
+                    let status = report
+                        .verifications
+                        .as_ref()
+                        .map_or_else(|| "unchecked".to_string(), |v| v[index].to_string());
+
+                    rows.push([
+                        report.day.to_string(),
+                        part.number().to_string(),
+                        report.answers[index].to_string(),
+                        status,
+                        Micros(report.parts[index].median).to_string(),
+                    ]);
+                }
+            }
+            Err(failure) => rows.push([
+                failure.day.to_string(),
+                "-".to_string(),
+                "-".to_string(),
+                format!("failed: {}", failure.message),
+                "-".to_string(),
+            ]),
+        }
+    }
+
+    rows.push([
+        "Total".to_string(),
+        String::new(),
+        String::new(),
+        String::new(),
+        Micros(total).to_string(),
+    ]);
+
+    let widths = (0..5)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join(" | ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_allocations(stats: &AllocationStats) -> String {
+    format!(
+        "{} allocations | {} bytes allocated | peak {} bytes live",
+        stats.count, stats.bytes, stats.peak_live_bytes
+    )
+}
+
+fn optional_field(value: Option<u64>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Quotes a CSV field when it contains a separator, a quote or a line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use solution::answer::Answer;
+
+    fn statistics(nanos: u64) -> Statistics {
+        let duration = Duration::from_nanos(nanos);
+
+        Statistics {
+            runs: 1,
+            min: duration,
+            median: duration,
+            mean: duration,
+            stddev: Duration::ZERO,
+        }
+    }
+
+    fn report() -> DayOutcome {
+        Ok(DayReport {
+            day: 5,
+            input_name: "input".to_string(),
+            answers: [Answer::from(4924), Answer::from("1,2")],
+            verifications: Some([Verification::Correct, Verification::Unknown]),
+            parse: statistics(100),
+            parts: [statistics(20), statistics(30)],
+            total: statistics(150),
+            allocations: None,
+        })
+    }
+
+    fn failure() -> DayOutcome {
+        Err(DayFailure {
+            day: 4,
+            message: "Panicked: Invalid character: Z".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let csv = render(OutputFormat::Csv, &[report()]);
+
+        assert_eq!(
+            format!(
+                "{CSV_HEADER}\n\
+                 5,1,input,4924,correct,4924,1,100,100,100,0,20,20,20,0,,,,,,,\n\
+                 5,2,input,\"1,2\",unknown,,1,100,100,100,0,30,30,30,0,,,,,,,"
+            ),
+            csv
+        );
+    }
+
+    #[test]
+    fn test_render_json() {
+        let json = render(OutputFormat::Json, &[report()]);
+        let document: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(1, document["version"]);
+        assert_eq!(2, document["records"].as_array().unwrap().len());
+        assert_eq!("4924", document["records"][0]["answer"]);
+        assert_eq!("correct", document["records"][0]["status"]);
+        assert_eq!(serde_json::Value::Null, document["records"][1]["expected"]);
+        assert_eq!(30, document["records"][1]["median_ns"]);
+    }
+
+    #[test]
+    fn test_render_allocations() {
+        let mut report = report().unwrap();
+        report.allocations = Some([
+            AllocationStats {
+                count: 3,
+                bytes: 1024,
+                peak_live_bytes: 512,
+            },
+            AllocationStats::default(),
+            AllocationStats {
+                count: 1,
+                bytes: 8,
+                peak_live_bytes: 8,
+            },
+        ]);
+        let outcomes = [Ok(report)];
+
+        assert!(render(OutputFormat::Csv, &outcomes).ends_with(",30,30,30,0,,3,1024,512,1,8,8"));
+        assert!(render(OutputFormat::Table, &outcomes).ends_with(
+            "  Allocations:\n\
+             \x20   Parse:    3 allocations | 1024 bytes allocated | peak 512 bytes live\n\
+             \x20   Part one: 0 allocations | 0 bytes allocated | peak 0 bytes live\n\
+             \x20   Part two: 1 allocations | 8 bytes allocated | peak 8 bytes live"
+        ));
+    }
+
+    #[test]
+    fn test_render_failure() {
+        let csv = render(OutputFormat::Csv, &[failure()]);
+
+        assert_eq!(
+            Some("4,1,,,failed,,0,0,0,0,0,0,0,0,0,Panicked: Invalid character: Z,,,,,,"),
+            csv.lines().nth(1)
+        );
+        assert_eq!(
+            "Day 4 failed: Panicked: Invalid character: Z",
+            render(OutputFormat::Table, &[failure()])
+        );
+    }
+
+    #[test]
+    fn test_render_summary() {
+        let mut report = report().unwrap();
+        // The median of whole runs needn't be the sum of the medians of their phases.
+        report.total = statistics(400);
+        let summary = render(OutputFormat::Summary, &[failure(), Ok(report)]);
+
+        assert_eq!(
+            "Day   | Part  | Answer | Status                                 | Time\n\
+             4     | -     | -      | failed: Panicked: Invalid character: Z | -\n\
+             5     | parse |        |                                        | 0.1µs\n\
+             5     | 1     | 4924   | correct                                | 0.0µs\n\
+             5     | 2     | 1,2    | unknown                                | 0.0µs\n\
+             Total |       |        |                                        | 0.1µs",
+            summary
+        );
+    }
+
+    #[test]
+    fn test_csv_header_matches_records() {
+        let json = serde_json::to_value(Record::from_failure(
+            failure().as_ref().unwrap_err(),
+            Part::One,
+        ))
+        .unwrap();
+        let fields = json
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut header = CSV_HEADER
+            .split(',')
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        header.sort();
+
+        assert_eq!(header, fields);
+    }
+}
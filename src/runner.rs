@@ -0,0 +1,363 @@
+use crate::allocation::AllocationStats;
+use crate::answers::{ExpectedAnswers, Verification};
+use crate::cli::RunArgs;
+use crate::days::{self, Day, DayRun};
+use crate::input::{InputResolver, InputSource};
+use crate::output;
+use crate::timing::{Repetition, Statistics};
+use solution::answer::Answer;
+use solution::example::Example;
+use solution::Part;
+use std::any::Any;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+/// The outcome of running one day on one input.
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: u8,
+    pub input_name: String,
+    pub answers: [Answer; 2],
+    /// The verification of each answer, when running with `--verify`.
+    pub verifications: Option<[Verification; 2]>,
+    pub parse: Statistics,
+    pub parts: [Statistics; 2],
+    pub total: Statistics,
+    /// The allocations of the parsing and of each part, with the `alloc-stats` feature.
+    pub allocations: Option<[AllocationStats; 3]>,
+}
+
+impl DayReport {
+    pub fn has_wrong_answer(&self) -> bool {
+        self.verifications.as_ref().is_some_and(|verifications| {
+            verifications
+                .iter()
+                .any(|verification| matches!(verification, Verification::Wrong(_)))
+        })
+    }
+}
+
+/// A day that couldn't be solved, because of an error or a panic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayFailure {
+    pub day: u8,
+    pub message: String,
+}
+
+/// The report of a day, or why it couldn't be solved.
+pub type DayOutcome = Result<DayReport, DayFailure>;
+
+/// Solves every selected day on its input and prints the answers along with the timing
+/// statistics of each phase, in the requested output format.
+///
+/// Days run concurrently on `--jobs` threads. A day failing, even by panicking, is reported as
+/// such without stopping the others, and makes the run fail. With `--verify`, each answer is
+/// checked against the expected answers file and the run fails if any of them is wrong. An
+/// example, read from an `examples` directory or with `--example`, is always checked against
+/// its own answers instead.
+pub fn run(args: &RunArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let selection = args.selection();
+    let days = days::all()
+        .into_iter()
+        .filter(|day| selection.contains(day.number))
+        .collect::<Vec<_>>();
+
+    if days.is_empty() {
+        return Err(Box::new(NoDaySelected));
+    }
+
+    if args.input.is_some() && days.len() > 1 {
+        return Err(Box::new(AmbiguousInput));
+    }
+
+    let input_resolver = InputResolver::from_env(args.input.as_deref()).with_mmap(args.mmap);
+    let expected_answers = if args.verify {
+        Some(ExpectedAnswers::load(&args.answers)?)
+    } else {
+        None
+    };
+
+    let outcomes = run_concurrently(&days, args.jobs(), |day| {
+        run_day(
+            day,
+            &input_resolver,
+            args.implementation.as_deref(),
+            args.example,
+            args.repetition(),
+            expected_answers.as_ref(),
+        )
+        .map_err(|err| DayFailure {
+            day: day.number,
+            message: err.to_string(),
+        })
+    });
+
+    println!("{}", output::render(args.format, &outcomes));
+
+    let failed = outcomes
+        .iter()
+        .any(|outcome| outcome.as_ref().map_or(true, DayReport::has_wrong_answer));
+
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// Applies `task` to every item on a pool of `jobs` threads, returning the results in the order
+/// of the items.
+fn run_concurrently<T, R, F>(items: &[T], jobs: usize, task: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next_item = AtomicUsize::new(0);
+    let results = Mutex::new(items.iter().map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next_item.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+
+                let result = task(item);
+                results
+                    .lock()
+                    .expect("No task panics while holding the lock")[index] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .expect("No task panics while holding the lock")
+        .into_iter()
+        .map(|result| result.expect("Every item is processed"))
+        .collect()
+}
+
+fn run_day(
+    day: &Day,
+    input_resolver: &InputResolver,
+    implementation: Option<&str>,
+    example: bool,
+    repetition: Repetition,
+    expected_answers: Option<&ExpectedAnswers>,
+) -> Result<DayReport, Box<dyn Error>> {
+    let solvers = day.select(implementation)?;
+    let input = input_resolver.resolve(day.number, Path::new(day.input))?;
+    let (source, example_answers) = if example || input.location.is_example() {
+        let example = Example::parse(&input.read()?)?;
+        // The lines of an example are counted from the end of its header, so they can't be
+        // matched with the lines of its file.
+        (InputSource::Text(example.input), Some(example.expected))
+    } else {
+        (input.source()?, None)
+    };
+
+    let mut runs = Vec::new();
+    let mut elapsed = Duration::ZERO;
+
+    while repetition.needs_another_run(runs.len(), elapsed) {
+        let reader = source.reader()?;
+        let day_run = panic::catch_unwind(AssertUnwindSafe(|| day.run(reader, solvers)))
+            .map_err(|payload| DayPanicked(panic_message(payload.as_ref())))??;
+
+        elapsed += day_run.total_time();
+        runs.push(day_run);
+    }
+
+    let input_name = input.name;
+    let statistics = |phase_time: fn(&DayRun) -> Duration| {
+        let samples = runs.iter().map(phase_time).collect::<Vec<_>>();
+
+        Statistics::from_samples(&samples).expect("A day is always run at least once")
+    };
+
+    let parse = statistics(|run| run.parse_time);
+    let parts = [
+        statistics(|run| run.part_one_time),
+        statistics(|run| run.part_two_time),
+    ];
+    let total = statistics(DayRun::total_time);
+
+    let last_run = runs.pop().expect("A day is always run at least once");
+    let answers = [last_run.part_one, last_run.part_two];
+    let allocations = last_run.allocations;
+
+    let verifications = match example_answers {
+        Some(expected) => Some(Part::ALL.map(|part| {
+            Verification::against(expected[part.index()].clone(), &answers[part.index()])
+        })),
+        None => expected_answers.map(|expected_answers| {
+            Part::ALL.map(|part| {
+                expected_answers.verify(day.number, &input_name, part, &answers[part.index()])
+            })
+        }),
+    };
+
+    Ok(DayReport {
+        day: day.number,
+        input_name,
+        answers,
+        verifications,
+        parse,
+        parts,
+        total,
+        allocations,
+    })
+}
+
+/// The message a panic was raised with, when it is a string.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Panicked without a message".to_string()
+    }
+}
+
+#[derive(Debug)]
+pub struct DayPanicked(String);
+
+impl Display for DayPanicked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Panicked: {}", self.0)
+    }
+}
+
+impl Error for DayPanicked {}
+
+#[derive(Debug)]
+pub struct NoDaySelected;
+
+impl Display for NoDaySelected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No solved day matches the selection")
+    }
+}
+
+impl Error for NoDaySelected {}
+
+#[derive(Debug)]
+pub struct AmbiguousInput;
+
+impl Display for AmbiguousInput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "--input can only be used when running a single day")
+    }
+}
+
+impl Error for AmbiguousInput {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use file_reader::file_reader::FileReader;
+    use solution::registry::{Solver, DEFAULT_NAME};
+
+    #[test]
+    fn test_run_concurrently_keeps_order() {
+        let items = (0..20).collect::<Vec<u64>>();
+
+        let results = run_concurrently(&items, 4, |item| {
+            thread::sleep(Duration::from_millis(20 - item));
+            item * 2
+        });
+
+        assert_eq!(
+            items.iter().map(|item| item * 2).collect::<Vec<_>>(),
+            results
+        );
+    }
+
+    #[test]
+    fn test_panicking_day_is_a_failure() {
+        fn parse(_: FileReader) -> Result<Box<dyn Any>, Box<dyn Error>> {
+            panic!("Invalid character: Z");
+        }
+        fn solve(_: &dyn Any) -> Answer {
+            unreachable!("The parsing panics")
+        }
+        static SOLVER: Solver = Solver {
+            day: 4,
+            part: Part::One,
+            name: DEFAULT_NAME,
+            solve,
+        };
+        let day = Day {
+            number: 4,
+            input: "unused",
+            parse,
+            solvers: [vec![&SOLVER], vec![&SOLVER]],
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("input");
+        std::fs::write(&input, "Z").unwrap();
+        let resolver = InputResolver::from_env(Some(&input));
+
+        let error = run_day(&day, &resolver, None, false, Repetition::Count(1), None)
+            .expect_err("The day panics");
+
+        assert_eq!("Panicked: Invalid character: Z", error.to_string());
+    }
+
+    #[test]
+    fn test_example_is_checked_against_its_answers() {
+        let day = days::all().into_iter().find(|day| day.number == 1).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("examples")).unwrap();
+        let input = dir.path().join("examples/example.txt");
+        std::fs::write(
+            &input,
+            "part-one: 11\npart-two: 30\n---\n3 4\n4 3\n2 5\n1 3\n3 9\n3 3\n",
+        )
+        .unwrap();
+        let resolver = InputResolver::from_env(Some(&input));
+
+        let report = run_day(&day, &resolver, None, false, Repetition::Count(1), None).unwrap();
+
+        assert_eq!(
+            Some([
+                Verification::Correct,
+                Verification::Wrong(Answer::from(30_u8))
+            ]),
+            report.verifications
+        );
+    }
+
+    #[test]
+    fn test_only_examples_are_read_as_such() {
+        let day = days::all().into_iter().find(|day| day.number == 1).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("input");
+        std::fs::write(&input, "part-one: 2\n---\n3 4\n").unwrap();
+        let resolver = InputResolver::from_env(Some(&input));
+
+        let error = run_day(&day, &resolver, None, false, Repetition::Count(1), None)
+            .expect_err("The header isn't part of the input");
+        assert!(error.to_string().contains(":1:"));
+
+        let report = run_day(&day, &resolver, None, true, Repetition::Count(1), None).unwrap();
+        assert_eq!(
+            Some([
+                Verification::Wrong(Answer::from(2_u8)),
+                Verification::Unknown
+            ]),
+            report.verifications
+        );
+    }
+}
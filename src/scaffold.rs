@@ -0,0 +1,192 @@
+use crate::cli::NewArgs;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::ExitCode;
+use toml_edit::{value, DocumentMut, InlineTable, Item, Value};
+
+const CARGO_TOML_TEMPLATE: &str = include_str!("../templates/day/Cargo.toml.template");
+const LIB_RS_TEMPLATE: &str = include_str!("../templates/day/lib.rs.template");
+const BUILD_RS_TEMPLATE: &str = include_str!("../templates/day/build.rs.template");
+const EXAMPLE_TEMPLATE: &str = include_str!("../templates/day/example.txt.template");
+
+/// Generates the crate of a new day and registers it in the workspace and the runner.
+pub fn new_day(args: &NewArgs) -> Result<ExitCode, Box<dyn Error>> {
+    scaffold(&args.root, args.day)?;
+    println!(
+        "Created day-{0}, put the puzzle input in day-{0}/src/input and the example with its answers in day-{0}/src/examples/example.txt",
+        args.day
+    );
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Creates `day-<day>` under the workspace at `root`.
+///
+/// Every edit is prepared before anything is written, and the crate is removed if writing it or
+/// the workspace manifest fails, so a failure leaves the workspace as it was.
+pub fn scaffold(root: &Path, day: u8) -> Result<(), Box<dyn Error>> {
+    let crate_name = format!("day-{day}");
+    let crate_dir = root.join(&crate_name);
+
+    if crate_dir.exists() {
+        return Err(Box::new(DayAlreadyExists(day)));
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = register_in_manifest(&fs::read_to_string(&manifest_path)?, &crate_name)?;
+
+    let written = write_crate(&crate_dir, day).and_then(|()| fs::write(&manifest_path, manifest));
+    if let Err(err) = written {
+        // The original error matters more than one met while cleaning up after it.
+        let _ = fs::remove_dir_all(&crate_dir);
+        return Err(Box::new(err));
+    }
+
+    Ok(())
+}
+
+fn write_crate(crate_dir: &Path, day: u8) -> io::Result<()> {
+    let src_dir = crate_dir.join("src");
+    fs::create_dir_all(src_dir.join("examples"))?;
+    fs::write(
+        crate_dir.join("Cargo.toml"),
+        render(CARGO_TOML_TEMPLATE, day),
+    )?;
+    fs::write(crate_dir.join("build.rs"), BUILD_RS_TEMPLATE)?;
+    fs::write(src_dir.join("lib.rs"), render(LIB_RS_TEMPLATE, day))?;
+    fs::write(src_dir.join("input"), "")?;
+    fs::write(
+        src_dir.join("examples").join("example.txt"),
+        EXAMPLE_TEMPLATE,
+    )
+}
+
+fn render(template: &str, day: u8) -> String {
+    template.replace("{{day}}", &day.to_string())
+}
+
+/// Adds the crate to the workspace members, the workspace dependencies and the dependencies of
+/// the runner, whose build script then links it in for the day to register itself.
+fn register_in_manifest(manifest: &str, crate_name: &str) -> Result<String, Box<dyn Error>> {
+    let mut document = manifest.parse::<DocumentMut>()?;
+    let malformed = || MalformedWorkspace("Cargo.toml");
+
+    document["workspace"]["members"]
+        .as_array_mut()
+        .ok_or_else(malformed)?
+        .push(crate_name);
+
+    let mut path = InlineTable::new();
+    path.insert("path", crate_name.into());
+    document["workspace"]["dependencies"]
+        .as_table_mut()
+        .ok_or_else(malformed)?
+        .insert(crate_name, value(path));
+
+    let mut workspace = InlineTable::new();
+    workspace.insert("workspace", true.into());
+    let dependencies = document["dependencies"]
+        .as_table_mut()
+        .ok_or_else(malformed)?;
+    dependencies.insert(crate_name, Item::Value(Value::InlineTable(workspace)));
+    dependencies.sort_values();
+
+    Ok(document.to_string())
+}
+
+#[derive(Debug)]
+pub struct DayAlreadyExists(u8);
+
+impl Display for DayAlreadyExists {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {} already exists", self.0)
+    }
+}
+
+impl Error for DayAlreadyExists {}
+
+#[derive(Debug)]
+pub struct MalformedWorkspace(&'static str);
+
+impl Display for MalformedWorkspace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Couldn't find where to register the new day in {}",
+            self.0
+        )
+    }
+}
+
+impl Error for MalformedWorkspace {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MANIFEST: &str = r#"[package]
+name = "advent-of-code-2024"
+
+[dependencies]
+clap = { workspace = true }
+day-1 = { workspace = true }
+solution = { workspace = true }
+
+[workspace]
+members = [
+    "day-1",
+]
+
+[workspace.dependencies]
+day-1 = { path = "day-1" }
+"#;
+
+    #[test]
+    fn test_register_in_manifest() -> Result<(), Box<dyn Error>> {
+        let manifest = register_in_manifest(MANIFEST, "day-2")?.parse::<DocumentMut>()?;
+
+        let members = manifest["workspace"]["members"].as_array().unwrap();
+        assert_eq!(
+            vec!["day-1", "day-2"],
+            members.iter().filter_map(Value::as_str).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some("day-2"),
+            manifest["workspace"]["dependencies"]["day-2"]["path"].as_str()
+        );
+        assert_eq!(
+            Some(true),
+            manifest["dependencies"]["day-2"]["workspace"].as_bool()
+        );
+
+        let dependencies = manifest["dependencies"].as_table().unwrap();
+        assert_eq!(
+            vec!["clap", "day-1", "day-2", "solution"],
+            dependencies.iter().map(|(key, _)| key).collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scaffold() -> Result<(), Box<dyn Error>> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("Cargo.toml"), MANIFEST)?;
+
+        scaffold(dir.path(), 2)?;
+
+        let lib = fs::read_to_string(dir.path().join("day-2/src/lib.rs"))?;
+        assert!(lib.contains("#[aoc(day = 2, part = 1)]"));
+        assert!(!lib.contains("{{day}}"));
+        assert!(dir.path().join("day-2/build.rs").is_file());
+        assert!(dir.path().join("day-2/src/input").is_file());
+        assert!(dir.path().join("day-2/src/examples/example.txt").is_file());
+
+        assert!(scaffold(dir.path(), 2).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+//! A local HTTP server standing in for the Advent of Code website in tests.
+
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use tiny_http::{Header, Response, Server};
+
+/// A request received by the stub server.
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Answers every request with the status and body returned by its responder, and records the
+/// requests it received.
+pub struct StubServer {
+    server: Arc<Server>,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl StubServer {
+    pub fn start<F>(responder: F) -> StubServer
+    where
+        F: Fn(&RecordedRequest) -> (u16, String) + Send + 'static,
+    {
+        let server = Arc::new(Server::http("127.0.0.1:0").expect("Couldn't start stub server"));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let handle = {
+            let server = Arc::clone(&server);
+            let requests = Arc::clone(&requests);
+
+            thread::spawn(move || {
+                for mut request in server.incoming_requests() {
+                    let mut body = String::new();
+                    request.as_reader().read_to_string(&mut body).ok();
+
+                    let recorded = RecordedRequest {
+                        method: request.method().to_string(),
+                        url: request.url().to_string(),
+                        headers: request
+                            .headers()
+                            .iter()
+                            .map(|header| (header.field.to_string(), header.value.to_string()))
+                            .collect(),
+                        body,
+                    };
+                    let (status, body) = responder(&recorded);
+                    requests.lock().unwrap().push(recorded);
+
+                    let content_type = Header::from_bytes("Content-Type", "text/html").unwrap();
+                    let response = Response::from_string(body)
+                        .with_status_code(status)
+                        .with_header(content_type);
+                    request.respond(response).ok();
+                }
+            })
+        };
+
+        StubServer {
+            server,
+            requests,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.server.server_addr())
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for StubServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+
+        if let Some(handle) = self.handle.take() {
+            handle.join().ok();
+        }
+    }
+}
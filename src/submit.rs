@@ -0,0 +1,355 @@
+use crate::answers::ExpectedAnswers;
+use crate::cli::SubmitArgs;
+use crate::client::{AocClient, ClientSettings};
+use crate::days;
+use crate::input::InputResolver;
+use solution::answer::Answer;
+use solution::Part;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Solves one part of a day on its input, submits the answer and records it in the expected
+/// answers file when the website accepts it.
+pub fn submit(args: &SubmitArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let day = days::all()
+        .into_iter()
+        .find(|day| day.number == args.day)
+        .ok_or(DayNotSolved(args.day))?;
+    let input = InputResolver::from_env(None).resolve(day.number, Path::new(day.input))?;
+    let day_run = day.run(input.source()?.reader()?, day.select(None)?)?;
+    let answer = match args.part {
+        Part::One => day_run.part_one,
+        Part::Two => day_run.part_two,
+    };
+
+    let outcome = submit_answer(
+        &args.client.settings()?,
+        &args.answers,
+        Submission {
+            day: day.number,
+            input_name: &input.name,
+            part: args.part,
+            answer: &answer,
+        },
+    )?;
+    println!("Day {} {}: {answer} is {outcome}", day.number, args.part);
+
+    Ok(match outcome {
+        SubmissionOutcome::Correct | SubmissionOutcome::AlreadySolved => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    })
+}
+
+/// An answer about to be submitted.
+pub struct Submission<'a> {
+    pub day: u8,
+    pub input_name: &'a str,
+    pub part: Part,
+    pub answer: &'a Answer,
+}
+
+/// Submits an answer unless the expected answers file already knows the right one, and records
+/// it there once the website confirms it is correct.
+pub fn submit_answer(
+    settings: &ClientSettings,
+    answers_path: &Path,
+    submission: Submission,
+) -> Result<SubmissionOutcome, Box<dyn Error>> {
+    let Submission {
+        day,
+        input_name,
+        part,
+        answer,
+    } = submission;
+    let mut expected_answers = ExpectedAnswers::load(answers_path)?;
+
+    match expected_answers.get(day, input_name, part) {
+        Some(expected) if expected == *answer => return Ok(SubmissionOutcome::AlreadySolved),
+        Some(expected) => return Err(Box::new(AlreadyAnswered(expected))),
+        None => {}
+    }
+
+    let response = AocClient::new(settings)?.submit(day, part, answer)?;
+    let outcome = SubmissionOutcome::from_response(&response);
+
+    if outcome == SubmissionOutcome::Correct {
+        expected_answers.set(day, input_name, part, answer);
+        expected_answers.save(answers_path)?;
+    }
+
+    Ok(outcome)
+}
+
+/// Whether an answer was too high or too low, when the website says so.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What the website made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    Incorrect(Option<Hint>),
+    RateLimited(Duration),
+    AlreadySolved,
+    /// A page that matched none of the known messages, reduced to its text.
+    Unrecognized(String),
+}
+
+impl SubmissionOutcome {
+    /// Reads the outcome of a submission from the HTML page the website answered with.
+    pub fn from_response(html: &str) -> SubmissionOutcome {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            SubmissionOutcome::Correct
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+
+            SubmissionOutcome::Incorrect(hint)
+        } else if text.contains("You gave an answer too recently") {
+            SubmissionOutcome::RateLimited(wait_time(&text).unwrap_or_default())
+        } else if text.contains("You don't seem to be solving the right level") {
+            SubmissionOutcome::AlreadySolved
+        } else {
+            SubmissionOutcome::Unrecognized(text)
+        }
+    }
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionOutcome::Correct => write!(f, "correct"),
+            SubmissionOutcome::Incorrect(None) => write!(f, "incorrect"),
+            SubmissionOutcome::Incorrect(Some(Hint::TooHigh)) => write!(f, "incorrect, too high"),
+            SubmissionOutcome::Incorrect(Some(Hint::TooLow)) => write!(f, "incorrect, too low"),
+            SubmissionOutcome::RateLimited(wait) => {
+                write!(f, "rate limited, retry in {}s", wait.as_secs())
+            }
+            SubmissionOutcome::AlreadySolved => write!(f, "already solved"),
+            SubmissionOutcome::Unrecognized(text) => write!(f, "unrecognized: {text}"),
+        }
+    }
+}
+
+/// The text of the `<article>` holding the message of the page, or of the whole page when there
+/// is none, without its tags.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map(|(article, _)| article);
+
+    let mut text = String::new();
+    // The split leaves the rest of the opening `<article ...>` tag at the start of the article.
+    let mut in_tag = article.is_some();
+    let article = article.unwrap_or(html);
+
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads the wait time of a message like `You have 1m 12s left to wait.`
+fn wait_time(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let number = number.parse::<u64>().ok()?;
+
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+#[derive(Debug)]
+pub struct DayNotSolved(u8);
+
+impl Display for DayNotSolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {} has no solution to submit", self.0)
+    }
+}
+
+impl Error for DayNotSolved {}
+
+#[derive(Debug)]
+pub struct AlreadyAnswered(Answer);
+
+impl Display for AlreadyAnswered {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The expected answers file already holds {}, refusing to submit a different one",
+            self.0
+        )
+    }
+}
+
+impl Error for AlreadyAnswered {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::stub_server::StubServer;
+    use std::fs;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let outcome = |message: &str| SubmissionOutcome::from_response(&page(message));
+
+        assert_eq!(
+            SubmissionOutcome::Correct,
+            outcome("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")
+        );
+        assert_eq!(
+            SubmissionOutcome::Incorrect(Some(Hint::TooHigh)),
+            outcome(
+                "That's not the right answer; your answer is too high.  Please wait one minute."
+            )
+        );
+        assert_eq!(
+            SubmissionOutcome::Incorrect(Some(Hint::TooLow)),
+            outcome("That's not the right answer; your answer is too low.")
+        );
+        assert_eq!(
+            SubmissionOutcome::Incorrect(None),
+            outcome("That's not the right answer.  If you're stuck, make sure you're using the full input data.")
+        );
+        assert_eq!(
+            SubmissionOutcome::RateLimited(Duration::from_secs(72)),
+            outcome("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 12s left to wait. <a href=\"/2024/day/5\">[Return to Day 5]</a>")
+        );
+        assert_eq!(
+            SubmissionOutcome::AlreadySolved,
+            outcome("You don't seem to be solving the right level.  Did you already complete it?")
+        );
+        assert_eq!(
+            SubmissionOutcome::Unrecognized("Something else".to_string()),
+            outcome("Something   else")
+        );
+    }
+
+    fn settings(base_url: String) -> ClientSettings {
+        ClientSettings {
+            base_url,
+            session: Some("secret".to_string()),
+            user_agent: "aoc-tests".to_string(),
+            throttle: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_submit_records_correct_answer() -> Result<(), Box<dyn Error>> {
+        let server = StubServer::start(|_| (200, page("That's the right answer!")));
+        let dir = tempfile::tempdir()?;
+        let answers_path = dir.path().join("answers.toml");
+        let submission = || Submission {
+            day: 3,
+            input_name: "input",
+            part: Part::Two,
+            answer: &Answer::Unsigned(42),
+        };
+
+        let outcome = submit_answer(&settings(server.url()), &answers_path, submission())?;
+        assert_eq!(SubmissionOutcome::Correct, outcome);
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!("POST", requests[0].method);
+        assert_eq!("/2024/day/3/answer", requests[0].url);
+        assert_eq!("level=2&answer=42", requests[0].body);
+        assert_eq!(
+            Some(Answer::from(42)),
+            ExpectedAnswers::load(&answers_path)?.get(3, "input", Part::Two)
+        );
+
+        let outcome = submit_answer(&settings(server.url()), &answers_path, submission())?;
+        assert_eq!(SubmissionOutcome::AlreadySolved, outcome);
+        assert_eq!(1, server.requests().len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_submit_does_not_record_wrong_answer() -> Result<(), Box<dyn Error>> {
+        let server = StubServer::start(|_| {
+            (
+                200,
+                page("That's not the right answer; your answer is too low."),
+            )
+        });
+        let dir = tempfile::tempdir()?;
+        let answers_path = dir.path().join("answers.toml");
+
+        let outcome = submit_answer(
+            &settings(server.url()),
+            &answers_path,
+            Submission {
+                day: 3,
+                input_name: "input",
+                part: Part::One,
+                answer: &Answer::Unsigned(7),
+            },
+        )?;
+
+        assert_eq!(SubmissionOutcome::Incorrect(Some(Hint::TooLow)), outcome);
+        assert!(!answers_path.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_submit_refuses_answer_contradicting_known_one() -> Result<(), Box<dyn Error>> {
+        let dir = tempfile::tempdir()?;
+        let answers_path = dir.path().join("answers.toml");
+        fs::write(&answers_path, "[day-3.input]\npart-one = 8\n")?;
+
+        let result = submit_answer(
+            &settings("http://127.0.0.1:9".to_string()),
+            &answers_path,
+            Submission {
+                day: 3,
+                input_name: "input",
+                part: Part::One,
+                answer: &Answer::Unsigned(7),
+            },
+        );
+
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
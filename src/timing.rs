@@ -0,0 +1,185 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// How many times a day is run before its timings are reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repetition {
+    /// Runs exactly this many times.
+    Count(usize),
+    /// Runs until the accumulated run time exceeds the budget, at least once.
+    Budget(Duration),
+}
+
+impl Repetition {
+    /// Whether another run is needed after `runs` runs taking `elapsed` in total.
+    pub fn needs_another_run(&self, runs: usize, elapsed: Duration) -> bool {
+        match self {
+            Repetition::Count(count) => runs < *count,
+            Repetition::Budget(budget) => runs == 0 || elapsed < *budget,
+        }
+    }
+}
+
+/// Summary statistics over the durations of repeated runs of one phase.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Statistics {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Statistics {
+    /// Computes the statistics of `samples`, or `None` if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Statistics> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted_samples = samples.to_vec();
+        sorted_samples.sort_unstable();
+
+        let runs = sorted_samples.len();
+        let middle = runs / 2;
+        let median = if runs.is_multiple_of(2) {
+            // Halving the difference rather than the sum, which may overflow.
+            sorted_samples[middle - 1] + (sorted_samples[middle] - sorted_samples[middle - 1]) / 2
+        } else {
+            sorted_samples[middle]
+        };
+
+        let mean = sorted_samples
+            .iter()
+            .map(Duration::as_secs_f64)
+            .sum::<f64>()
+            / runs as f64;
+        let variance = sorted_samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+
+        Some(Statistics {
+            runs,
+            min: sorted_samples[0],
+            median,
+            // Rounding may take statistics of the longest durations past the largest one.
+            mean: Duration::try_from_secs_f64(mean).unwrap_or(Duration::MAX),
+            stddev: Duration::try_from_secs_f64(variance.sqrt()).unwrap_or(Duration::MAX),
+        })
+    }
+}
+
+impl Display for Statistics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {} | median {} | mean {} | stddev {}",
+            Micros(self.min),
+            Micros(self.median),
+            Micros(self.mean),
+            Micros(self.stddev)
+        )
+    }
+}
+
+/// Displays a duration in microseconds, the unit used for every timing printed by the runner.
+pub struct Micros(pub Duration);
+
+impl Display for Micros {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.1}µs", self.0.as_secs_f64() * 1_000_000.0)
+    }
+}
+
+/// Parses a duration written as a number followed by `ns`, `us`, `µs`, `ms`, `s` or `m`.
+pub fn parse_duration(duration: &str) -> Result<Duration, InvalidDuration> {
+    let duration = duration.trim();
+    let unit_start = duration
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .ok_or_else(|| InvalidDuration(duration.to_string()))?;
+    let (value, unit) = duration.split_at(unit_start);
+
+    let value = f64::from_str(value).map_err(|_| InvalidDuration(duration.to_string()))?;
+    let seconds = match unit {
+        "ns" => value / 1_000_000_000.0,
+        "us" | "µs" => value / 1_000_000.0,
+        "ms" => value / 1_000.0,
+        "s" => value,
+        "m" => value * 60.0,
+        _ => return Err(InvalidDuration(duration.to_string())),
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|_| InvalidDuration(duration.to_string()))
+}
+
+#[derive(Debug)]
+pub struct InvalidDuration(String);
+
+impl Display for InvalidDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid duration: {}, expected e.g. `500ms` or `2s`",
+            self.0
+        )
+    }
+}
+
+impl Error for InvalidDuration {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_statistics() {
+        let samples = [4, 1, 3, 2].map(Duration::from_micros);
+
+        let statistics = Statistics::from_samples(&samples).unwrap();
+
+        assert_eq!(4, statistics.runs);
+        assert_eq!(Duration::from_micros(1), statistics.min);
+        assert_eq!(Duration::from_nanos(2500), statistics.median);
+        assert_eq!(Duration::from_nanos(2500), statistics.mean);
+        assert_eq!(1118, statistics.stddev.as_nanos());
+    }
+
+    #[test]
+    fn test_statistics_of_the_longest_durations() {
+        let statistics = Statistics::from_samples(&[Duration::MAX, Duration::MAX]).unwrap();
+
+        assert_eq!(Duration::MAX, statistics.median);
+        assert_eq!(Duration::MAX, statistics.mean);
+    }
+
+    #[test]
+    fn test_statistics_without_samples() {
+        assert_eq!(None, Statistics::from_samples(&[]));
+    }
+
+    #[test]
+    fn test_repetition() {
+        assert!(Repetition::Count(2).needs_another_run(1, Duration::ZERO));
+        assert!(!Repetition::Count(2).needs_another_run(2, Duration::ZERO));
+
+        let budget = Repetition::Budget(Duration::from_millis(10));
+        assert!(budget.needs_another_run(0, Duration::from_secs(1)));
+        assert!(budget.needs_another_run(3, Duration::from_millis(9)));
+        assert!(!budget.needs_another_run(4, Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(Duration::from_millis(500), parse_duration("500ms").unwrap());
+        assert_eq!(Duration::from_millis(1500), parse_duration("1.5s").unwrap());
+        assert_eq!(Duration::from_micros(20), parse_duration("20µs").unwrap());
+        assert!(parse_duration("20").is_err());
+        assert!(parse_duration("twos").is_err());
+        assert!(parse_duration("2h").is_err());
+        assert!(parse_duration("99999999999999999999999s").is_err());
+    }
+}
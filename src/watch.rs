@@ -0,0 +1,260 @@
+use crate::cli::WatchArgs;
+use crate::days;
+use crate::input::{InputLocation, InputResolver};
+use crate::output::SCHEMA_VERSION;
+use crate::timing::Micros;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::process::{Command, ExitCode, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Time without new events after which a burst of changes is considered over.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watches the sources and inputs of a day, rebuilding and re-running it on every change.
+///
+/// Runs go through `cargo run`, so that the changed sources are compiled in, and print the new
+/// answers and timings along with what changed since the previous run.
+pub fn watch(args: &WatchArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let day = days::all()
+        .into_iter()
+        .find(|day| day.number == args.day)
+        .ok_or(DayNotFound(args.day))?;
+    let src_dir = args.root.join(format!("day-{}", day.number)).join("src");
+
+    let mut watched_files = HashSet::new();
+    if let Ok(input) = InputResolver::from_env(None).resolve(day.number, Path::new(day.input)) {
+        if let InputLocation::File(path) = input.location {
+            watched_files.insert(path);
+        }
+    }
+    watched_files.insert(args.answers.clone());
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    watcher.watch(&src_dir, RecursiveMode::Recursive)?;
+    // Files are watched through their directory, as editors often replace a file rather than
+    // write to it, which would end a watch on the file itself.
+    for directory in watched_files.iter().filter_map(|path| path.parent()) {
+        if !directory.starts_with(&src_dir) {
+            watcher.watch(directory, RecursiveMode::NonRecursive)?;
+        }
+    }
+
+    println!(
+        "Watching {} and the input of day {}",
+        src_dir.display(),
+        day.number
+    );
+    let mut previous = run_once(args, None);
+    let is_relevant = |event: &Event| {
+        !matches!(event.kind, EventKind::Access(_))
+            && event
+                .paths
+                .iter()
+                .any(|path| path.starts_with(&src_dir) || watched_files.contains(path))
+    };
+
+    while let Ok(event) = receiver.recv() {
+        if !is_relevant(&event?) {
+            continue;
+        }
+
+        // Saving several files at once sends a burst of events, which should trigger one run.
+        while receiver.recv_timeout(DEBOUNCE).is_ok() {}
+
+        println!("\nChange detected, re-running day {}", day.number);
+        previous = run_once(args, previous.as_deref()).or(previous);
+        // Running the day reads its input, which must not trigger another run.
+        while receiver.try_recv().is_ok() {}
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Rebuilds and runs the day, or its tests, and prints the outcome against the `previous` run.
+/// Returns the records of the run, if it succeeded, for the next run to be compared against.
+fn run_once(args: &WatchArgs, previous: Option<&[RunRecord]>) -> Option<Vec<RunRecord>> {
+    if args.tests {
+        let status = cargo(args, "test")
+            .args(["--package", &format!("day-{}", args.day)])
+            .status();
+
+        match status {
+            Ok(status) if status.success() => println!("Tests passed"),
+            Ok(_) => println!("Tests failed"),
+            Err(err) => println!("Couldn't run cargo: {err}"),
+        }
+
+        return None;
+    }
+
+    let output = cargo(args, "run")
+        .args(["--bin", "aoc", "--", "run"])
+        .arg(args.day.to_string())
+        .arg("--verify")
+        .arg("--answers")
+        .arg(&args.answers)
+        .args(["--format", "json"])
+        .stderr(Stdio::inherit())
+        .output();
+
+    let records = output
+        .map_err(|err| err.to_string())
+        .and_then(|output| parse_records(&output.stdout));
+
+    match records {
+        Ok(records) => {
+            println!("{}", render_records(&records, previous));
+            Some(records)
+        }
+        Err(err) => {
+            println!("Run failed: {err}");
+            None
+        }
+    }
+}
+
+fn cargo(args: &WatchArgs, subcommand: &str) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command
+        .current_dir(&args.root)
+        .args([subcommand, "--quiet"]);
+
+    if args.release {
+        command.arg("--release");
+    }
+
+    command
+}
+
+/// The fields of a record of the JSON output the watch mode displays.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+struct RunRecord {
+    part: u8,
+    answer: String,
+    status: String,
+    expected: Option<String>,
+    median_ns: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct RunDocument {
+    version: u32,
+    records: Vec<RunRecord>,
+}
+
+fn parse_records(json: &[u8]) -> Result<Vec<RunRecord>, String> {
+    let document = serde_json::from_slice::<RunDocument>(json).map_err(|err| err.to_string())?;
+
+    if document.version != SCHEMA_VERSION {
+        return Err(format!(
+            "Unsupported output version {}, expected {SCHEMA_VERSION}",
+            document.version
+        ));
+    }
+
+    Ok(document.records)
+}
+
+/// Renders one line per part, noting what changed since the `previous` run.
+fn render_records(records: &[RunRecord], previous: Option<&[RunRecord]>) -> String {
+    records
+        .iter()
+        .map(|record| {
+            let previous = previous
+                .and_then(|previous| previous.iter().find(|other| other.part == record.part));
+            let status = match (record.status.as_str(), &record.expected) {
+                ("wrong", Some(expected)) => format!("wrong, expected {expected}"),
+                (status, _) => status.to_string(),
+            };
+            let median = Duration::from_nanos(record.median_ns);
+            let mut line = format!(
+                "  Part {}: {} ({status}) | median {}",
+                record.part,
+                record.answer,
+                Micros(median)
+            );
+
+            if let Some(previous) = previous {
+                if previous.median_ns > 0 {
+                    let change =
+                        (record.median_ns as f64 / previous.median_ns as f64 - 1.0) * 100.0;
+                    line.push_str(&format!(" ({change:+.1}%)"));
+                }
+                if previous.answer != record.answer {
+                    line.push_str(&format!(" | answer was {}", previous.answer));
+                }
+                if previous.status != record.status {
+                    line.push_str(&format!(" | status was {}", previous.status));
+                }
+            }
+
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[derive(Debug)]
+pub struct DayNotFound(u8);
+
+impl Display for DayNotFound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {} has no crate to watch", self.0)
+    }
+}
+
+impl Error for DayNotFound {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record(part: u8, answer: &str, status: &str, median_ns: u64) -> RunRecord {
+        RunRecord {
+            part,
+            answer: answer.to_string(),
+            status: status.to_string(),
+            expected: (status == "wrong").then(|| "6".to_string()),
+            median_ns,
+        }
+    }
+
+    #[test]
+    fn test_parse_records() {
+        let json = br#"{"version": 1, "records": [{"day": 6, "part": 2, "input": "input",
+            "answer": "3", "status": "wrong", "expected": "6", "runs": 1, "median_ns": 1500}]}"#;
+
+        assert_eq!(Ok(vec![record(2, "3", "wrong", 1500)]), parse_records(json));
+        assert!(parse_records(br#"{"version": 99, "records": []}"#).is_err());
+    }
+
+    #[test]
+    fn test_render_diff_against_previous_run() {
+        let previous = [
+            record(1, "41", "correct", 2000),
+            record(2, "3", "wrong", 1000),
+        ];
+        let current = [
+            record(1, "41", "correct", 1000),
+            record(2, "6", "correct", 1500),
+        ];
+
+        assert_eq!(
+            "  Part 1: 41 (correct) | median 1.0µs (-50.0%)\n\
+             \x20 Part 2: 6 (correct) | median 1.5µs (+50.0%) | answer was 3 | status was wrong",
+            render_records(&current, Some(&previous))
+        );
+        assert_eq!(
+            "  Part 2: 3 (wrong, expected 6) | median 1.0µs",
+            render_records(&previous[1..], None)
+        );
+    }
+}
@@ -0,0 +1,42 @@
+//! Runs the runner built with the release profile, whose settings the unit tests don't see.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_release_build_reports_a_panicking_day_and_runs_the_others() {
+    let inputs = tempfile::tempdir().unwrap();
+    // Concatenating the two numbers overflows a `usize`, which makes day 7 panic.
+    fs::write(inputs.path().join("day-7"), "1: 99999999999 99999999999\n").unwrap();
+
+    let output = Command::new(env!("CARGO"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args([
+            "run",
+            "--quiet",
+            "--release",
+            "--bin",
+            "aoc",
+            "--target-dir",
+        ])
+        .arg(Path::new(env!("CARGO_TARGET_TMPDIR")).join("release"))
+        .args(["--", "run", "6..=7", "--format", "summary"])
+        .env("AOC_INPUT_DIR", inputs.path())
+        .output()
+        .unwrap();
+    let summary = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(
+        Some(1),
+        output.status.code(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        summary.contains("7     | -     | -      | failed: Panicked"),
+        "{summary}"
+    );
+    assert!(summary.contains("6     | 1"), "{summary}");
+}
@@ -0,0 +1,91 @@
+//! Generates a day with `aoc new` in a workspace of its own, and checks it against the lints of
+//! the repository.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use toml_edit::{Array, DocumentMut, Item, Table, Value};
+
+const ROOT: &str = env!("CARGO_MANIFEST_DIR");
+
+/// A workspace whose dependencies are the ones of the repository, without any day.
+fn workspace_manifest() -> String {
+    let manifest = fs::read_to_string(Path::new(ROOT).join("Cargo.toml"))
+        .unwrap()
+        .parse::<DocumentMut>()
+        .unwrap();
+    let mut dependencies = manifest["workspace"]["dependencies"]
+        .as_table()
+        .unwrap()
+        .clone();
+    dependencies.retain(|name, _| !name.starts_with("day-"));
+    for (_, dependency) in dependencies.iter_mut() {
+        if let Some(path) = dependency
+            .as_inline_table_mut()
+            .and_then(|dependency| dependency.get_mut("path"))
+        {
+            let absolute = Path::new(ROOT).join(path.as_str().unwrap());
+            *path = absolute.to_str().unwrap().into();
+        }
+    }
+
+    let mut document = DocumentMut::new();
+    let mut package = Table::new();
+    package.insert("name", toml_edit::value("scratch"));
+    package.insert("version", toml_edit::value("0.1.0"));
+    package.insert("edition", toml_edit::value("2024"));
+    document.insert("package", Item::Table(package));
+    document.insert("dependencies", Item::Table(Table::new()));
+
+    let mut workspace = Table::new();
+    workspace.insert("members", Item::Value(Value::Array(Array::new())));
+    workspace.insert("dependencies", Item::Table(dependencies));
+    document.insert("workspace", Item::Table(workspace));
+
+    document.to_string()
+}
+
+#[test]
+fn test_scaffolded_day_passes_clippy() {
+    let workspace = tempfile::tempdir().unwrap();
+    fs::write(workspace.path().join("Cargo.toml"), workspace_manifest()).unwrap();
+    fs::create_dir(workspace.path().join("src")).unwrap();
+    fs::write(workspace.path().join("src/lib.rs"), "").unwrap();
+    // Resolves the same versions as the repository, which are then already downloaded.
+    if let Ok(lock) = fs::read(Path::new(ROOT).join("Cargo.lock")) {
+        fs::write(workspace.path().join("Cargo.lock"), lock).unwrap();
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["new", "9", "--root"])
+        .arg(workspace.path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = Command::new(env!("CARGO"))
+        .current_dir(workspace.path())
+        .args([
+            "clippy",
+            "--quiet",
+            "-p",
+            "day-9",
+            "--all-targets",
+            "--target-dir",
+        ])
+        .arg(Path::new(env!("CARGO_TARGET_TMPDIR")).join("scaffold"))
+        .args(["--", "-D", "warnings"])
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,332 @@
+//! Attributes registering the parser and solvers of a day with the runner.
+//!
+//! ```rust,ignore
+//! #[aoc_parse(day = 7)]
+//! fn parse(reader: FileReader) -> Result<Vec<Calibration>, Box<dyn Error>> { ... }
+//!
+//! #[aoc(day = 7, part = 2, name = "pruned")]
+//! fn part_two_pruned(calibrations: &[Calibration]) -> usize { ... }
+//!
+//! #[aoc_generator(day = 7)]
+//! fn generate(rng: &mut GeneratorRng, lines: usize) -> String { ... }
+//! ```
+//!
+//! The annotated functions are left untouched, the registration being emitted next to them.
+//! See `solution::registry` for how the runner finds them.
+//!
+//! `examples!` generates the tests of the examples of a day, found in its `src/examples`.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use syn::meta::ParseNestedMeta;
+use syn::parse::Parser;
+use syn::spanned::Spanned;
+use syn::{FnArg, Ident, ItemFn, LitInt, LitStr, Type, parse_macro_input, parse_quote};
+
+/// Registers a function as the solver of one part of a day.
+///
+/// Takes the `day`, the `part` (`1` or `2`) and an optional `name` telling apart several
+/// solvers of the same part, `"default"` being the one run unless another one is selected.
+///
+/// The function takes a reference to the input produced by the parser of the day and returns
+/// anything convertible into an `Answer`. A `&[T]` or `&str` parameter reads a `Vec<T>` or a
+/// `String` input.
+#[proc_macro_attribute]
+pub fn aoc(args: TokenStream, item: TokenStream) -> TokenStream {
+    let function = parse_macro_input!(item as ItemFn);
+    let mut day = None;
+    let mut part = None;
+    let mut name = None;
+
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("day") {
+            day = Some(parse_day(&meta)?);
+        } else if meta.path.is_ident("part") {
+            part = Some(parse_part(&meta)?);
+        } else if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse::<LitStr>()?);
+        } else {
+            return Err(meta.error("expected `day`, `part` or `name`"));
+        }
+
+        Ok(())
+    });
+    parse_macro_input!(args with parser);
+
+    let (Some(day), Some(part)) = (day, part) else {
+        return error(
+            &function.sig,
+            "expected `#[aoc(day = <day>, part = <1|2>)]`",
+        );
+    };
+    let name = name.map_or_else(
+        || quote!(::solution::registry::DEFAULT_NAME),
+        |name| quote!(#name),
+    );
+    let input_type = match input_type(&function) {
+        Ok(input_type) => input_type,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let function_name = &function.sig.ident;
+
+    quote! {
+        #function
+
+        // Named so as not to shadow the annotated function, which may be named alike.
+        const _: () = {
+            fn __aoc_solve(input: &dyn ::std::any::Any) -> ::solution::answer::Answer {
+                let input = ::solution::registry::downcast_input::<#input_type>(
+                    input, #day, #part, #name,
+                );
+                #function_name(input).into()
+            }
+
+            ::solution::inventory::submit! {
+                ::solution::registry::Solver {
+                    day: #day,
+                    part: #part,
+                    name: #name,
+                    solve: __aoc_solve,
+                }
+            }
+        };
+    }
+    .into()
+}
+
+/// Registers a function as the parser of a day, whose input is read by the solvers of both
+/// parts.
+///
+/// The function takes a `FileReader` and returns a `Result` of the parsed input, with any error
+/// convertible into a `Box<dyn Error>`.
+#[proc_macro_attribute]
+pub fn aoc_parse(args: TokenStream, item: TokenStream) -> TokenStream {
+    let function = parse_macro_input!(item as ItemFn);
+    let day = match day_argument(args) {
+        Ok(day) => day,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let Some(day) = day else {
+        return error(&function.sig, "expected `#[aoc_parse(day = <day>)]`");
+    };
+    let function_name = &function.sig.ident;
+
+    quote! {
+        #function
+
+        const _: () = {
+            fn __aoc_parse(
+                reader: ::solution::file_reader::file_reader::FileReader,
+            ) -> Result<Box<dyn ::std::any::Any>, Box<dyn ::std::error::Error>> {
+                Ok(Box::new(#function_name(reader)?))
+            }
+
+            ::solution::inventory::submit! {
+                ::solution::registry::Parser {
+                    day: #day,
+                    input: ::solution::input_path!(),
+                    parse: __aoc_parse,
+                }
+            }
+        };
+    }
+    .into()
+}
+
+/// Registers a function as the generator of random inputs of a day.
+///
+/// The function takes a `&mut GeneratorRng` and the size of the input, and returns the input.
+#[proc_macro_attribute]
+pub fn aoc_generator(args: TokenStream, item: TokenStream) -> TokenStream {
+    let function = parse_macro_input!(item as ItemFn);
+    let day = match day_argument(args) {
+        Ok(day) => day,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let Some(day) = day else {
+        return error(&function.sig, "expected `#[aoc_generator(day = <day>)]`");
+    };
+    let function_name = &function.sig.ident;
+
+    quote! {
+        #function
+
+        ::solution::inventory::submit! {
+            ::solution::registry::Generator { day: #day, generate: #function_name }
+        }
+    }
+    .into()
+}
+
+/// Generates one test per example of a day, checking the answers of a solution against it.
+///
+/// Takes either a type implementing `Solution`, or the `day` whose registered parser and default
+/// solvers are checked.
+///
+/// Every `src/examples/<name>.txt` file of the crate is an example, tested by a test named after
+/// it. The crate needs a build script asking to rerun on changes to `src/examples`, for an added
+/// example to trigger a new expansion.
+///
+/// ```rust,ignore
+/// #[cfg(test)]
+/// mod test {
+///     use super::*;
+///
+///     solution::examples!(Day8);
+///     // or, for a day registered through the attributes:
+///     solution::examples!(day = 8);
+/// }
+/// ```
+#[proc_macro]
+pub fn examples(input: TokenStream) -> TokenStream {
+    let mut tokens = input.clone().into_iter();
+    let names_day = matches!(
+        (tokens.next(), tokens.next()),
+        (Some(proc_macro::TokenTree::Ident(ident)), Some(proc_macro::TokenTree::Punct(punct)))
+            if ident.to_string() == "day" && punct.as_char() == '='
+    );
+    let check = if names_day {
+        match day_argument(input) {
+            Ok(Some(day)) => quote!(check_registered(#day)),
+            Ok(None) => unreachable!("The day is named"),
+            Err(err) => return err.to_compile_error().into(),
+        }
+    } else {
+        let solution = parse_macro_input!(input as Type);
+        quote!(check::<#solution>())
+    };
+    let examples = match example_files() {
+        Ok(examples) if !examples.is_empty() => examples,
+        Ok(_) => return error(&check, "expected at least one `src/examples/*.txt` file"),
+        Err(err) => return error(&check, &format!("couldn't list `src/examples`: {err}")),
+    };
+
+    let tests = examples.iter().map(|(name, path)| {
+        let path = path.to_string_lossy();
+
+        quote! {
+            #[test]
+            fn #name() -> ::std::result::Result<(), ::std::boxed::Box<dyn ::std::error::Error>> {
+                ::solution::example::Example::parse(include_str!(#path))?.#check
+            }
+        }
+    });
+
+    quote!(#(#tests)*).into()
+}
+
+/// The examples of the crate being compiled, with the name of their test, sorted by name.
+fn example_files() -> std::io::Result<Vec<(Ident, PathBuf)>> {
+    let dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap_or_default())
+        .join("src")
+        .join("examples");
+    let mut examples = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|extension| extension != "txt") {
+            continue;
+        }
+        let Some(stem) = path.file_stem() else {
+            continue;
+        };
+
+        let name = stem
+            .to_string_lossy()
+            .chars()
+            .map(|character| {
+                if character.is_ascii_alphanumeric() {
+                    character
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+        // Names which aren't identifiers, such as `1` or `type`, are prefixed into one.
+        let name = syn::parse_str::<Ident>(&name)
+            .unwrap_or_else(|_| Ident::new(&format!("example_{name}"), Span::call_site()));
+
+        examples.push((name, path));
+    }
+    examples.sort_by(|(_, path), (_, other)| path.cmp(other));
+
+    Ok(examples)
+}
+
+/// Parses the arguments of the attributes only taking a `day`.
+fn day_argument(args: TokenStream) -> syn::Result<Option<u8>> {
+    let mut day = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("day") {
+            day = Some(parse_day(&meta)?);
+            Ok(())
+        } else {
+            Err(meta.error("expected `day`"))
+        }
+    });
+
+    Parser::parse(parser, args)?;
+
+    Ok(day)
+}
+
+fn parse_day(meta: &ParseNestedMeta) -> syn::Result<u8> {
+    let day = meta.value()?.parse::<LitInt>()?;
+
+    match day.base10_parse::<u8>() {
+        Ok(number @ 1..=25) => Ok(number),
+        _ => Err(syn::Error::new(
+            day.span(),
+            "expected a day between 1 and 25",
+        )),
+    }
+}
+
+fn parse_part(meta: &ParseNestedMeta) -> syn::Result<TokenStream2> {
+    let part = meta.value()?.parse::<LitInt>()?;
+
+    match part.base10_parse::<u8>() {
+        Ok(1) => Ok(quote!(::solution::Part::One)),
+        Ok(2) => Ok(quote!(::solution::Part::Two)),
+        _ => Err(syn::Error::new(part.span(), "expected part `1` or `2`")),
+    }
+}
+
+/// The type of the parsed input a solver reads, from its first parameter.
+fn input_type(function: &ItemFn) -> syn::Result<Type> {
+    let Some(FnArg::Typed(parameter)) = function.sig.inputs.first() else {
+        return Err(syn::Error::new(
+            function.sig.span(),
+            "expected a solver taking a reference to the parsed input",
+        ));
+    };
+    let Type::Reference(reference) = parameter.ty.as_ref() else {
+        return Err(syn::Error::new(
+            parameter.ty.span(),
+            "expected a reference to the parsed input",
+        ));
+    };
+
+    Ok(match reference.elem.as_ref() {
+        Type::Slice(slice) => {
+            let element = &slice.elem;
+            parse_quote!(::std::vec::Vec<#element>)
+        }
+        Type::Path(path) if path.qself.is_none() && path.path.is_ident("str") => {
+            parse_quote!(::std::string::String)
+        }
+        other => other.clone(),
+    })
+}
+
+fn error(spanned: &impl Spanned, message: &str) -> TokenStream {
+    syn::Error::new(spanned.span(), message)
+        .to_compile_error()
+        .into()
+}
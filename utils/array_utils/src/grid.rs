@@ -0,0 +1,177 @@
+use crate::coordinate::Coordinates;
+use crate::{DirectionMove, TableUtils};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+
+/// A rectangular table, whose rows all have the same width.
+///
+/// Rows are read through [`Deref`] and elements through [`TableUtils`], with `x` being the
+/// column and `y` the row.
+///
+/// # Examples
+/// ```rust
+/// use array_utils::TableUtils;
+/// use array_utils::grid::Grid;
+///
+/// let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+///
+/// assert_eq!((3, 2), (grid.width(), grid.height()));
+/// assert_eq!(Some(&6), grid.get_from_coordinates(2, 1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    rows: Vec<Vec<T>>,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    /// Makes a grid of `rows`, the width of the grid being the one of its first row.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first row whose width differs from the one of the first row.
+    pub fn new(rows: Vec<Vec<T>>) -> Result<Grid<T>, RaggedRow> {
+        let width = rows.first().map_or(0, Vec::len);
+
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(RaggedRow {
+                y,
+                width: row.len(),
+                expected: width,
+            });
+        }
+
+        Ok(Grid { rows, width })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Whether `(x, y)` is a position of the grid.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height()
+    }
+
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        self.rows
+    }
+
+    /// Applies `transform` to every element, along with its coordinates.
+    pub fn map<U>(self, mut transform: impl FnMut(T, Coordinates) -> U) -> Grid<U> {
+        let rows = self
+            .rows
+            .into_iter()
+            .enumerate()
+            .map(|(y, row)| {
+                row.into_iter()
+                    .enumerate()
+                    .map(|(x, element)| transform(element, Coordinates { x, y }))
+                    .collect()
+            })
+            .collect();
+
+        Grid {
+            rows,
+            width: self.width,
+        }
+    }
+}
+
+impl<T> Deref for Grid<T> {
+    type Target = [Vec<T>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.rows
+    }
+}
+
+impl<T> TableUtils<T> for Grid<T> {
+    fn iter_table_with_coordinates<'a>(&'a self) -> impl Iterator<Item = (&'a T, Coordinates)>
+    where
+        T: 'a,
+    {
+        self.rows.iter_table_with_coordinates()
+    }
+
+    fn get_from_coordinates(&self, x: usize, y: usize) -> Option<&T> {
+        self.rows.get_from_coordinates(x, y)
+    }
+
+    fn get_from_coordinate_move<U: Into<Coordinates>>(
+        &self,
+        coordinate: U,
+        direction: DirectionMove,
+    ) -> Option<&T> {
+        self.rows.get_from_coordinate_move(coordinate, direction)
+    }
+
+    fn get_mut_from_coordinates_move<U: Into<Coordinates>>(
+        &mut self,
+        coordinate: U,
+        direction: DirectionMove,
+    ) -> Option<&mut T> {
+        self.rows
+            .get_mut_from_coordinates_move(coordinate, direction)
+    }
+}
+
+/// A row of a grid whose width differs from the one of the first row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaggedRow {
+    pub y: usize,
+    pub width: usize,
+    pub expected: usize,
+}
+
+impl Display for RaggedRow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "row {} is {} wide, while the grid is {} wide",
+            self.y, self.width, self.expected
+        )
+    }
+}
+
+impl Error for RaggedRow {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let grid = Grid::new(vec![vec!['a', 'b'], vec!['c', 'd'], vec!['e', 'f']]).unwrap();
+
+        assert_eq!(2, grid.width());
+        assert_eq!(3, grid.height());
+        assert!(grid.contains(1, 2));
+        assert!(!grid.contains(2, 0));
+        assert_eq!(
+            Err(RaggedRow {
+                y: 1,
+                width: 1,
+                expected: 2
+            }),
+            Grid::new(vec![vec![1, 2], vec![3]])
+        );
+        assert_eq!(0, Grid::<u8>::new(Vec::new()).unwrap().width());
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = Grid::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+
+        assert_eq!(
+            vec![vec![(1, 0, 0), (2, 1, 0)], vec![(3, 0, 1), (4, 1, 1)]],
+            grid.map(|element, coordinates| (element, coordinates.x, coordinates.y))
+                .into_rows()
+        );
+    }
+}
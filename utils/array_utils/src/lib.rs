@@ -1,4 +1,5 @@
 pub mod coordinate;
+pub mod grid;
 
 use crate::coordinate::Coordinates;
 use DirectionMove::{Down, DownLeft, DownRight, Left, Right, Up, UpLeft, UpRight};
@@ -65,9 +66,11 @@ impl<T> TableUtils<T> for Vec<Vec<T>> {
     where
         T: 'a,
     {
-        let width = self.len();
-        let height = self[0].len();
-        (0..width).flat_map(move |y| (0..height).map(move |x| (&self[y][x], Coordinates { x, y })))
+        self.iter().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, element)| (element, Coordinates { x, y }))
+        })
     }
 
     fn get_from_coordinates(&self, x: usize, y: usize) -> Option<&T> {
@@ -157,6 +160,9 @@ pub enum DirectionMove {
 }
 
 impl DirectionMove {
+    pub const ALL: [DirectionMove; 8] =
+        [Up, Down, Left, Right, UpLeft, UpRight, DownLeft, DownRight];
+
     pub fn get_direction(&self) -> (isize, isize) {
         match self {
             Up => (0, -1),
@@ -181,6 +187,9 @@ impl DirectionMove {
 #[cfg(test)]
 mod test {
     use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest::sample::select;
 
     #[test]
     fn test_pairwise() {
@@ -200,8 +209,8 @@ mod test {
         let mut iter = table.iter_table_with_coordinates();
 
         assert_eq!(Some((&1, Coordinates { x: 0, y: 0 })), iter.next());
-        assert_eq!(Some((&2, Coordinates { x: 0, y: 1 })), iter.next());
-        assert_eq!(Some((&3, Coordinates { x: 1, y: 0 })), iter.next());
+        assert_eq!(Some((&2, Coordinates { x: 1, y: 0 })), iter.next());
+        assert_eq!(Some((&3, Coordinates { x: 0, y: 1 })), iter.next());
         assert_eq!(Some((&4, Coordinates { x: 1, y: 1 })), iter.next());
         assert_eq!(None, iter.next());
     }
@@ -227,4 +236,89 @@ mod test {
         assert_eq!(Some(&2), table.get_from_coordinate_move((0, 1), UpRight));
         assert_eq!(None, table.get_from_coordinate_move((0, 0), UpLeft));
     }
+
+    #[test]
+    fn test_iter_table_with_coordinates_of_a_wide_table() {
+        let table = vec![vec![1, 2, 3]];
+
+        assert_eq!(
+            vec![
+                (&1, Coordinates { x: 0, y: 0 }),
+                (&2, Coordinates { x: 1, y: 0 }),
+                (&3, Coordinates { x: 2, y: 0 }),
+            ],
+            table.iter_table_with_coordinates().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            0,
+            Vec::<Vec<u8>>::new().iter_table_with_coordinates().count()
+        );
+    }
+
+    /// Tables of any shape, including empty and ragged ones.
+    fn tables() -> impl Strategy<Value = Vec<Vec<u16>>> {
+        vec(vec(any::<u16>(), 0..12), 0..12)
+    }
+
+    fn directions() -> impl Strategy<Value = DirectionMove> {
+        select(DirectionMove::ALL.to_vec())
+    }
+
+    proptest! {
+        #[test]
+        fn test_iterated_coordinates_round_trip(table in tables()) {
+            let mut count = 0;
+
+            for (element, coordinates) in table.iter_table_with_coordinates() {
+                let found = table.get_from_coordinates(coordinates.x, coordinates.y);
+
+                prop_assert!(found.is_some_and(|found| std::ptr::eq(found, element)));
+                count += 1;
+            }
+
+            prop_assert_eq!(table.iter().map(Vec::len).sum::<usize>(), count);
+        }
+
+        #[test]
+        fn test_move_coordinates_follows_direction(
+            x in 0..100_usize,
+            y in 0..100_usize,
+            direction in directions(),
+        ) {
+            let (dx, dy) = direction.get_direction();
+            let expected = x.checked_add_signed(dx).zip(y.checked_add_signed(dy));
+
+            prop_assert_eq!(expected, move_coordinates((x, y), direction));
+        }
+
+        #[test]
+        fn test_opposite_moves_cancel_out(
+            x in 0..100_usize,
+            y in 0..100_usize,
+            direction in directions(),
+        ) {
+            let opposite = DirectionMove::ALL
+                .into_iter()
+                .filter(|other| direction.is_opposed_to(*other))
+                .collect::<Vec<_>>();
+            prop_assert_eq!(1, opposite.len());
+
+            if let Some(moved) = move_coordinates((x, y), direction) {
+                prop_assert_eq!(Some((x, y)), move_coordinates(moved, opposite[0]));
+            }
+        }
+
+        #[test]
+        fn test_get_from_coordinate_move_follows_move_coordinates(
+            table in tables(),
+            x in 0..14_usize,
+            y in 0..14_usize,
+            direction in directions(),
+        ) {
+            let expected = move_coordinates((x, y), direction)
+                .and_then(|(x, y)| table.get_from_coordinates(x, y));
+
+            prop_assert_eq!(expected, table.get_from_coordinate_move((x, y), direction));
+        }
+    }
 }
@@ -1,10 +1,22 @@
+use crate::legend::{Legend, MarkedGrid, UnknownCharacter};
+use crate::mapped::MappedFile;
+use crate::parse::{FieldError, Line, ParseError};
+use array_utils::coordinate::Coordinates;
+use array_utils::grid::{Grid, RaggedRow};
+use std::collections::HashMap;
+use std::fmt::Display;
 use std::fs::File;
 use std::io::prelude::*;
-use std::io::{BufReader, Error};
+use std::io::{BufReader, Cursor, Error, ErrorKind};
 use std::path::PathBuf;
+use std::str::{FromStr, Utf8Error};
 
 pub struct FileReader {
-    reader: BufReader<File>,
+    reader: Box<dyn BufRead + Send>,
+    /// The path of the file read, named by parse errors.
+    path: Option<PathBuf>,
+    /// The number of lines of the file before the content read, which is not `0` for a section.
+    skipped_lines: usize,
 }
 
 impl FileReader {
@@ -24,11 +36,57 @@ impl FileReader {
     pub fn new(path_to_file_string: &str) -> Result<FileReader, Error> {
         let path_to_file = PathBuf::from(path_to_file_string);
 
-        let file = File::open(path_to_file)?;
+        let file = File::open(&path_to_file)?;
 
         let buf_reader = BufReader::new(file.try_clone()?);
 
-        Ok(FileReader { reader: buf_reader })
+        Ok(FileReader {
+            reader: Box::new(buf_reader),
+            path: Some(path_to_file),
+            skipped_lines: 0,
+        })
+    }
+
+    /// Constructs a `FileReader` over the file at `path` mapped in memory, whose lines are
+    /// parsed in place by [`FileReader::parse_lines_with`] rather than copied.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the file cannot be opened or mapped.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified, by this process or any other, until the reader is dropped.
+    /// See [`MappedFile::open`].
+    pub unsafe fn mapped(path: impl Into<PathBuf>) -> Result<FileReader, Error> {
+        // SAFETY: upheld by the caller.
+        let file = unsafe { MappedFile::open(path)? };
+
+        Ok(FileReader {
+            path: Some(file.path().to_path_buf()),
+            reader: Box::new(Cursor::new(file)),
+            skipped_lines: 0,
+        })
+    }
+
+    /// Constructs a `FileReader` over content already held in memory, so it can be read the same
+    /// way as a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The content to read.
+    pub fn from_string(content: String) -> FileReader {
+        FileReader {
+            reader: Box::new(Cursor::new(content)),
+            path: None,
+            skipped_lines: 0,
+        }
+    }
+
+    /// Names the file the content read comes from, so that parse errors point to it.
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> FileReader {
+        self.path = Some(path.into());
+        self
     }
 
     /// Reads a line from the file.
@@ -36,6 +94,8 @@ impl FileReader {
     /// # Returns
     ///
     /// * `Option<String>` - The line read from the file, or `None` if the end of the file has been reached or an error occurred.
+    ///
+    /// Use [`FileReader::try_lines`] to tell a read error apart from the end of the file.
     pub fn read_line(&mut self) -> Option<String> {
         let mut line = String::new();
 
@@ -45,9 +105,348 @@ impl FileReader {
         }
     }
 
+    /// Turns the reader into an iterator over its lines which, unlike iterating the reader
+    /// itself, yields the errors met while reading instead of stopping at them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use file_reader::file_reader::FileReader;
+    ///
+    /// let mut lines = FileReader::from_string("a\nb\n".to_string()).try_lines().strip_newlines();
+    ///
+    /// assert_eq!("a", lines.next().unwrap().unwrap());
+    /// assert_eq!(1, lines.line_number());
+    /// assert_eq!("b", lines.next().unwrap().unwrap());
+    /// assert!(lines.next().is_none());
+    /// ```
+    pub fn try_lines(self) -> Lines {
+        Lines {
+            reader: self.reader,
+            line_number: self.skipped_lines,
+            strip_newlines: false,
+            failed: false,
+        }
+    }
+
+    /// Parses every line, without its newline and surrounding whitespace, into a `T`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use file_reader::file_reader::FileReader;
+    ///
+    /// let reader = FileReader::from_string("1\n2\nthree\n".to_string());
+    /// let err = reader.parse_lines::<u32>().unwrap_err();
+    ///
+    /// assert_eq!("<input>:3:1: invalid digit found in string (`three`)", err.to_string());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error locating the first line which can't be read or parsed.
+    pub fn parse_lines<T: FromStr>(self) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: Display,
+    {
+        self.parse_lines_with(|line| line.parse(line.text().trim()))
+    }
+
+    /// Parses every line with `parse`, which is handed the line without its newline.
+    ///
+    /// Lines are borrowed from the buffer of the reader, which for a [mapped](FileReader::mapped)
+    /// file or content held in memory is the whole of it, so that they are only copied when
+    /// they straddle the end of the buffer.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use file_reader::file_reader::FileReader;
+    ///
+    /// let reader = FileReader::from_string("1 2\n3 4\n".to_string());
+    /// let pairs = reader.parse_lines_with(|line| {
+    ///     let (left, right) = line
+    ///         .text()
+    ///         .split_once(' ')
+    ///         .ok_or_else(|| line.missing("a second number"))?;
+    ///
+    ///     Ok((line.parse::<u32>(left)?, line.parse::<u32>(right)?))
+    /// });
+    ///
+    /// assert_eq!(vec![(1, 2), (3, 4)], pairs.unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error locating the first line which can't be read or parsed.
+    pub fn parse_lines_with<T>(
+        mut self,
+        mut parse: impl FnMut(Line<'_>) -> Result<T, FieldError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut parse_line = |bytes: &[u8], line_number| {
+            let bytes = bytes.strip_suffix(b"\r").unwrap_or(bytes);
+            let line = std::str::from_utf8(bytes)
+                .map_err(|err| ParseError::Read(invalid_utf8(line_number, err)))?;
+
+            parse(Line::new(line)).map_err(|err| err.locate(self.path.clone(), line_number))
+        };
+        let mut parsed = Vec::new();
+        let mut line_number = self.skipped_lines;
+        // The start of a line which goes on past the end of the buffer.
+        let mut straddling = Vec::new();
+
+        loop {
+            let buffer = self
+                .reader
+                .fill_buf()
+                .map_err(|err| ParseError::Read(read_failed(line_number + 1, &err)))?;
+
+            let Some(end) = buffer.iter().position(|byte| *byte == b'\n') else {
+                if buffer.is_empty() {
+                    break;
+                }
+
+                straddling.extend_from_slice(buffer);
+                let length = buffer.len();
+                self.reader.consume(length);
+                continue;
+            };
+
+            line_number += 1;
+            if straddling.is_empty() {
+                parsed.push(parse_line(&buffer[..end], line_number)?);
+            } else {
+                straddling.extend_from_slice(&buffer[..end]);
+                parsed.push(parse_line(&straddling, line_number)?);
+                straddling.clear();
+            }
+            self.reader.consume(end + 1);
+        }
+
+        if !straddling.is_empty() {
+            parsed.push(parse_line(&straddling, line_number + 1)?);
+        }
+
+        Ok(parsed)
+    }
+
+    /// Reads a grid of characters, each one converted into a `T`.
+    ///
+    /// Empty lines before and after the grid are ignored, while spaces are read as cells like any
+    /// other character.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use file_reader::file_reader::FileReader;
+    ///
+    /// let grid = FileReader::from_string("..#\n#..\n".to_string()).read_grid::<char>().unwrap();
+    /// assert_eq!((3, 2), (grid.width(), grid.height()));
+    ///
+    /// let err = FileReader::from_string("12\n3x\n".to_string()).read_grid::<Digit>().unwrap_err();
+    /// assert_eq!(
+    ///     "<input>:2:2: invalid character at (1, 1): not a digit (`x`)",
+    ///     err.to_string()
+    /// );
+    ///
+    /// #[derive(Debug)]
+    /// struct Digit(u32);
+    ///
+    /// impl TryFrom<char> for Digit {
+    ///     type Error = &'static str;
+    ///
+    ///     fn try_from(character: char) -> Result<Self, Self::Error> {
+    ///         character.to_digit(10).map(Digit).ok_or("not a digit")
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error locating the first character which can't be converted, or the first row
+    /// which isn't as wide as the first one.
+    pub fn read_grid<T: TryFrom<char>>(self) -> Result<Grid<T>, ParseError>
+    where
+        T::Error: Display,
+    {
+        self.read_grid_with(|character, _| T::try_from(character))
+    }
+
+    /// Reads a grid of characters told apart by `legend`, the markers being replaced by the
+    /// terrain under them and their positions collected.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use array_utils::coordinate::Coordinates;
+    /// use file_reader::file_reader::FileReader;
+    /// use file_reader::legend::Legend;
+    ///
+    /// let legend = Legend::new().terrain('.', false).terrain('#', true).marker('^', false);
+    /// let marked = FileReader::from_string("..#\n.^.\n".to_string())
+    ///     .read_marked_grid(&legend)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(vec![vec![false, false, true], vec![false; 3]], marked.grid.into_rows());
+    /// assert_eq!(vec![Coordinates { x: 1, y: 1 }], marked.markers[&'^']);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error locating the first character which isn't in the legend, or the first row
+    /// which isn't as wide as the first one.
+    pub fn read_marked_grid<T: Clone>(
+        self,
+        legend: &Legend<T>,
+    ) -> Result<MarkedGrid<T>, ParseError> {
+        let mut markers: HashMap<char, Vec<Coordinates>> = HashMap::new();
+
+        let grid = self.read_grid_with(|character, coordinates| {
+            let (tile, is_marker) = legend.read(character).ok_or(UnknownCharacter(character))?;
+
+            if is_marker {
+                markers.entry(character).or_default().push(coordinates);
+            }
+
+            Ok::<_, UnknownCharacter>(tile)
+        })?;
+
+        Ok(MarkedGrid { grid, markers })
+    }
+
+    /// Reads a grid of characters, each one converted along with its coordinates by `convert`.
+    fn read_grid_with<T, E: Display>(
+        self,
+        mut convert: impl FnMut(char, Coordinates) -> Result<T, E>,
+    ) -> Result<Grid<T>, ParseError> {
+        let path = self.path.clone();
+        let locate = |line, column, text, message| ParseError::Invalid {
+            path: path.clone(),
+            line,
+            column,
+            text,
+            message,
+        };
+        let mut lines = self.try_lines().strip_newlines();
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        let mut ended = false;
+
+        while let Some(line) = lines.next() {
+            let line = line.map_err(ParseError::Read)?;
+            let line_number = lines.line_number();
+
+            if line.is_empty() {
+                ended = !rows.is_empty();
+                continue;
+            }
+            if ended {
+                return Err(locate(
+                    line_number,
+                    1,
+                    String::new(),
+                    "the grid goes on after a blank line".to_string(),
+                ));
+            }
+
+            let y = rows.len();
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(x, character)| {
+                    convert(character, Coordinates { x, y }).map_err(|err| {
+                        locate(
+                            line_number,
+                            x + 1,
+                            character.to_string(),
+                            format!("invalid character at ({x}, {y}): {err}"),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if let Some(expected) = rows
+                .first()
+                .map(Vec::len)
+                .filter(|width| *width != row.len())
+            {
+                let ragged_row = RaggedRow {
+                    y,
+                    width: row.len(),
+                    expected,
+                };
+                return Err(locate(
+                    line_number,
+                    row.len().min(expected) + 1,
+                    String::new(),
+                    ragged_row.to_string(),
+                ));
+            }
+
+            rows.push(row);
+        }
+
+        Ok(Grid::new(rows).expect("Every row is as wide as the first one"))
+    }
+
+    /// Splits what is left of the file into sections separated by blank lines, so that each of
+    /// them can be parsed on its own.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use file_reader::file_reader::FileReader;
+    ///
+    /// let reader = FileReader::from_string("47|53\n97|13\n\n75,47,61\n".to_string());
+    /// let sections = reader.sections().unwrap();
+    ///
+    /// assert_eq!(2, sections.len());
+    /// assert_eq!(4, sections[1].first_line());
+    /// assert_eq!(vec!["75,47,61"], sections[1].reader().parse_lines::<String>().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the file cannot be read or is not valid UTF-8.
+    pub fn sections(self) -> Result<Vec<Section>, Error> {
+        let path = self.path.clone();
+        let mut lines = self.try_lines();
+        let mut sections = Vec::new();
+        let mut current: Option<Section> = None;
+
+        while let Some(line) = lines.next() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                sections.extend(current.take());
+            } else {
+                current
+                    .get_or_insert_with(|| Section {
+                        path: path.clone(),
+                        first_line: lines.line_number(),
+                        text: String::new(),
+                    })
+                    .text
+                    .push_str(&line);
+            }
+        }
+        sections.extend(current);
+
+        Ok(sections)
+    }
+
+    /// Reads what is left of the file into a single string.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the file cannot be read or is not valid UTF-8.
+    pub fn read_to_string(mut self) -> Result<String, Error> {
+        let mut contents = String::new();
+        self.reader.read_to_string(&mut contents)?;
+
+        Ok(contents)
+    }
+
+    /// Reads the whole file at `path` into a string.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the file cannot be opened or read.
     pub fn read_file(path: &str) -> Result<String, Error> {
-        let path_to_project: PathBuf = PathBuf::from(path);
-        let path_to_file = path_to_project.join(path);
+        let path_to_file = PathBuf::from(path);
 
         let file = File::open(path_to_file)?;
         let mut buf_reader = BufReader::new(file);
@@ -67,6 +466,106 @@ impl Iterator for FileReader {
     }
 }
 
+/// The lines of a [`FileReader`], each one either read or the error met while reading it.
+///
+/// Created by [`FileReader::try_lines`]. Lines keep their newline unless
+/// [`strip_newlines`](Lines::strip_newlines) is called. The iterator ends after the first error
+/// met while reading, which would most likely be met again on every later line.
+pub struct Lines {
+    reader: Box<dyn BufRead + Send>,
+    line_number: usize,
+    strip_newlines: bool,
+    failed: bool,
+}
+
+impl Lines {
+    /// Removes the `\n` or `\r\n` ending every line.
+    pub fn strip_newlines(mut self) -> Self {
+        self.strip_newlines = true;
+        self
+    }
+
+    /// The 1-based number of the last line yielded, or `0` before the first one.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+}
+
+impl Iterator for Lines {
+    type Item = Result<String, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let mut bytes = Vec::new();
+        let line_number = self.line_number + 1;
+
+        match self.reader.read_until(b'\n', &mut bytes) {
+            Ok(0) => return None,
+            Ok(_) => self.line_number = line_number,
+            Err(err) => {
+                self.failed = true;
+                return Some(Err(read_failed(line_number, &err)));
+            }
+        }
+
+        if self.strip_newlines && bytes.ends_with(b"\n") {
+            bytes.pop();
+            if bytes.ends_with(b"\r") {
+                bytes.pop();
+            }
+        }
+
+        Some(String::from_utf8(bytes).map_err(|err| invalid_utf8(line_number, err.utf8_error())))
+    }
+}
+
+fn read_failed(line_number: usize, err: &Error) -> Error {
+    Error::new(
+        err.kind(),
+        format!("Failed to read line {line_number}: {err}"),
+    )
+}
+
+fn invalid_utf8(line_number: usize, err: Utf8Error) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("Line {line_number} is not valid UTF-8: {err}"),
+    )
+}
+
+/// Lines of a file between blank lines, as split by [`FileReader::sections`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    path: Option<PathBuf>,
+    first_line: usize,
+    text: String,
+}
+
+impl Section {
+    /// The 1-based number of the first line of the section in its file.
+    pub fn first_line(&self) -> usize {
+        self.first_line
+    }
+
+    /// The lines of the section, with their newlines.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// A reader over the lines of the section, whose parse errors point to their lines in the
+    /// file.
+    pub fn reader(&self) -> FileReader {
+        FileReader {
+            reader: Box::new(Cursor::new(self.text.clone())),
+            path: self.path.clone(),
+            skipped_lines: self.first_line - 1,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs::File;
@@ -89,6 +588,34 @@ mod tests {
         assert_eq!(file_reader.next(), None);
     }
 
+    #[test]
+    fn reads_whole_file_to_string() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("test.txt");
+        let mut file = File::create(&file_path).unwrap();
+        writeln!(file, "Hello\nWorld").unwrap();
+
+        let file_reader = FileReader::new(file_path.to_str().unwrap()).unwrap();
+        assert_eq!(file_reader.read_to_string().unwrap(), "Hello\nWorld\n");
+    }
+
+    #[test]
+    fn reads_file_from_relative_path() {
+        // Tests run from the directory of the crate, whose files are only read.
+        let manifest = FileReader::read_file("src/../Cargo.toml").unwrap();
+
+        assert!(manifest.starts_with("[package]\nname = \"file_reader\"\n"));
+    }
+
+    #[test]
+    fn reads_lines_from_string() {
+        let mut file_reader = FileReader::from_string("Hello\nWorld".to_string());
+
+        assert_eq!(file_reader.next(), Some("Hello\n".to_string()));
+        assert_eq!(file_reader.next(), Some("World".to_string()));
+        assert_eq!(file_reader.next(), None);
+    }
+
     #[test]
     fn return_err_when_path_does_not_exist() {
         let file_reader = FileReader::new("non_existent_file.txt");
@@ -105,4 +632,288 @@ mod tests {
         let mut file_reader = FileReader::new(file_path.to_str().unwrap()).unwrap();
         assert_eq!(file_reader.next(), None);
     }
+
+    #[test]
+    fn tries_lines_with_their_number() {
+        let mut lines = FileReader::from_string("Hello\r\nWorld\n\nAgain".to_string())
+            .try_lines()
+            .strip_newlines();
+
+        assert_eq!(0, lines.line_number());
+        assert_eq!("Hello", lines.next().unwrap().unwrap());
+        assert_eq!("World", lines.next().unwrap().unwrap());
+        assert_eq!("", lines.next().unwrap().unwrap());
+        assert_eq!("Again", lines.next().unwrap().unwrap());
+        assert_eq!(4, lines.line_number());
+        assert!(lines.next().is_none());
+
+        let lines = FileReader::from_string("Hello\nWorld".to_string()).try_lines();
+        assert_eq!(
+            vec!["Hello\n".to_string(), "World".to_string()],
+            lines.collect::<Result<Vec<_>, _>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn tries_lines_naming_the_line_that_is_not_utf8() {
+        let reader = FileReader {
+            reader: Box::new(Cursor::new(b"Hello\nW\xF6rld\nAgain\n".to_vec())),
+            path: None,
+            skipped_lines: 0,
+        };
+        let mut lines = reader.try_lines().strip_newlines();
+
+        assert_eq!("Hello", lines.next().unwrap().unwrap());
+
+        let err = lines.next().unwrap().unwrap_err();
+        assert_eq!(ErrorKind::InvalidData, err.kind());
+        assert!(err.to_string().starts_with("Line 2 is not valid UTF-8"));
+
+        assert_eq!("Again", lines.next().unwrap().unwrap());
+    }
+
+    /// Reads one line then fails, as a file on a failing disk would.
+    struct FailingReader {
+        read: bool,
+    }
+
+    impl Read for FailingReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.read {
+                return Err(Error::other("disk failure"));
+            }
+
+            self.read = true;
+            let line = b"Hello\n";
+            buf[..line.len()].copy_from_slice(line);
+
+            Ok(line.len())
+        }
+    }
+
+    #[test]
+    fn tries_lines_surfacing_read_errors() {
+        let reader = FileReader {
+            reader: Box::new(BufReader::new(FailingReader { read: false })),
+            path: None,
+            skipped_lines: 0,
+        };
+        let mut lines = reader.try_lines();
+
+        assert_eq!("Hello\n", lines.next().unwrap().unwrap());
+        assert_eq!(
+            "Failed to read line 2: disk failure",
+            lines.next().unwrap().unwrap_err().to_string()
+        );
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn parses_lines_locating_errors_in_the_file() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("input");
+        let mut file = File::create(&file_path).unwrap();
+        writeln!(file, "7: 1 2\n8: 3 x").unwrap();
+
+        let parse = |reader: FileReader| {
+            reader.parse_lines_with(|line| {
+                let (result, numbers) = line
+                    .text()
+                    .split_once(':')
+                    .ok_or_else(|| line.missing("`:`"))?;
+                let numbers = numbers
+                    .split_whitespace()
+                    .map(|number| line.parse::<u32>(number))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok((line.parse::<u32>(result)?, numbers))
+            })
+        };
+
+        let err = parse(FileReader::new(file_path.to_str().unwrap()).unwrap()).unwrap_err();
+        assert_eq!(
+            format!(
+                "{}:2:6: invalid digit found in string (`x`)",
+                file_path.display()
+            ),
+            err.to_string()
+        );
+
+        let parsed = parse(FileReader::from_string("7: 1 2\n".to_string())).unwrap();
+        assert_eq!(vec![(7, vec![1, 2])], parsed);
+
+        let err =
+            parse(FileReader::from_string("7 1 2\n".to_string()).with_path("input")).unwrap_err();
+        assert_eq!("input:1:6: missing `:`", err.to_string());
+    }
+
+    #[test]
+    fn splits_sections_on_blank_lines() {
+        let reader = FileReader::from_string("\na\nb\n\n  \n\nc\r\n\n".to_string());
+        let sections = reader.sections().unwrap();
+
+        assert_eq!(
+            vec![(2, "a\nb\n"), (7, "c\r\n")],
+            sections
+                .iter()
+                .map(|section| (section.first_line(), section.text()))
+                .collect::<Vec<_>>()
+        );
+        assert!(FileReader::from_string("\n\n".to_string())
+            .sections()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn parses_sections_locating_errors_in_the_file() {
+        let reader =
+            FileReader::from_string("1|2\n3|4\n\n5,6\n7,x\n".to_string()).with_path("input");
+        let sections = reader.sections().unwrap();
+
+        let rules = sections[0]
+            .reader()
+            .parse_lines_with(|line| {
+                let (left, right) = line
+                    .text()
+                    .split_once('|')
+                    .ok_or_else(|| line.missing("`|`"))?;
+                Ok((line.parse::<u32>(left)?, line.parse::<u32>(right)?))
+            })
+            .unwrap();
+        assert_eq!(vec![(1, 2), (3, 4)], rules);
+
+        let err = sections[1]
+            .reader()
+            .parse_lines_with(|line| {
+                line.text()
+                    .split(',')
+                    .map(|number| line.parse::<u32>(number))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .unwrap_err();
+        assert_eq!(
+            "input:5:3: invalid digit found in string (`x`)",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn reads_grid_checking_its_rows() {
+        let grid = FileReader::from_string("\n.#.\r\n#.#\n\n\n".to_string())
+            .read_grid::<char>()
+            .unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(vec!['#', '.', '#'], grid[1]);
+
+        let reader = FileReader::from_string("...\n..\n".to_string()).with_path("map");
+        assert_eq!(
+            "map:2:3: row 1 is 2 wide, while the grid is 3 wide",
+            reader.read_grid::<char>().unwrap_err().to_string()
+        );
+
+        let reader = FileReader::from_string("...\n\n...\n".to_string());
+        assert_eq!(
+            "<input>:3:1: the grid goes on after a blank line",
+            reader.read_grid::<char>().unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn reads_spaces_as_grid_cells() {
+        let grid = FileReader::from_string("# \n  \n".to_string())
+            .read_grid::<char>()
+            .unwrap();
+
+        assert_eq!(vec![vec!['#', ' '], vec![' ', ' ']], grid.into_rows());
+    }
+
+    #[test]
+    fn reads_marked_grid() {
+        let legend = Legend::new()
+            .terrain('.', 0)
+            .terrain('#', 1)
+            .marker('^', 0)
+            .other_markers(2);
+        let marked = FileReader::from_string("^.a\n#a^\n".to_string())
+            .read_marked_grid(&legend)
+            .unwrap();
+
+        assert_eq!(vec![vec![0, 0, 2], vec![1, 2, 0]], marked.grid.into_rows());
+        assert_eq!(
+            vec![Coordinates { x: 0, y: 0 }, Coordinates { x: 2, y: 1 }],
+            marked.markers[&'^']
+        );
+        assert_eq!(
+            vec![Coordinates { x: 2, y: 0 }, Coordinates { x: 1, y: 1 }],
+            marked.markers[&'a']
+        );
+        assert_eq!(2, marked.markers.len());
+
+        let legend = Legend::new().terrain('.', ()).marker('S', ());
+        let reader = FileReader::from_string("..\n.E\n".to_string()).with_path("map");
+        assert_eq!(
+            "map:2:2: invalid character at (1, 1): 'E' is not in the legend (`E`)",
+            reader.read_marked_grid(&legend).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn parses_lines_straddling_the_buffer() {
+        let content = "12 345\r\n6 78\n\n9 10";
+        let reader = FileReader {
+            reader: Box::new(BufReader::with_capacity(4, content.as_bytes())),
+            path: None,
+            skipped_lines: 0,
+        };
+
+        let lines = reader
+            .parse_lines_with(|line| Ok(line.text().to_string()))
+            .unwrap();
+        assert_eq!(vec!["12 345", "6 78", "", "9 10"], lines);
+
+        let reader = FileReader {
+            reader: Box::new(BufReader::with_capacity(3, &b"1\n23\n4\xF65\n"[..])),
+            path: None,
+            skipped_lines: 0,
+        };
+        assert_eq!(
+            "Line 3 is not valid UTF-8: invalid utf-8 sequence of 1 bytes from index 1",
+            reader
+                .parse_lines_with(|line| Ok(line.text().len()))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn parses_mapped_file_lines() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("input");
+        std::fs::write(&file_path, "1 2\n3 x\n").unwrap();
+        let path = file_path.to_str().unwrap();
+
+        // SAFETY: the file is only written by this test, before and after being read.
+        let err = unsafe { FileReader::mapped(path) }
+            .unwrap()
+            .parse_lines_with(|line| {
+                line.text()
+                    .split(' ')
+                    .map(|number| line.parse::<u32>(number))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .unwrap_err();
+        assert_eq!(
+            format!("{path}:2:3: invalid digit found in string (`x`)"),
+            err.to_string()
+        );
+
+        std::fs::write(&file_path, "").unwrap();
+        // SAFETY: as above.
+        let lines = unsafe { FileReader::mapped(path) }
+            .unwrap()
+            .parse_lines_with(|line| Ok(line.text().len()))
+            .unwrap();
+        assert!(lines.is_empty());
+    }
 }
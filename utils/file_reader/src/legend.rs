@@ -0,0 +1,122 @@
+use array_utils::coordinate::Coordinates;
+use array_utils::grid::Grid;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Tells apart the characters of a grid standing for terrain from the ones marking a position,
+/// such as the start of a walk or an antenna.
+///
+/// Markers are replaced in the grid by the terrain lying under them, and their positions are
+/// collected by [`FileReader::read_marked_grid`].
+///
+/// # Examples
+/// ```rust
+/// use file_reader::legend::Legend;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Tile {
+///     Floor,
+///     Wall,
+/// }
+///
+/// let legend = Legend::new()
+///     .terrain('.', Tile::Floor)
+///     .terrain('#', Tile::Wall)
+///     .marker('S', Tile::Floor);
+///
+/// assert_eq!(Some(Tile::Wall), legend.tile('#'));
+/// assert_eq!(Some(Tile::Floor), legend.tile('S'));
+/// assert_eq!(None, legend.tile('E'));
+/// ```
+///
+/// [`FileReader::read_marked_grid`]: crate::file_reader::FileReader::read_marked_grid
+#[derive(Debug, Clone)]
+pub struct Legend<T> {
+    symbols: HashMap<char, Symbol<T>>,
+    /// The terrain under any character the legend doesn't name, which is then a marker.
+    other_markers: Option<T>,
+}
+
+#[derive(Debug, Clone)]
+enum Symbol<T> {
+    Terrain(T),
+    Marker(T),
+}
+
+impl<T: Clone> Legend<T> {
+    pub fn new() -> Self {
+        Legend {
+            symbols: HashMap::new(),
+            other_markers: None,
+        }
+    }
+
+    /// Reads `character` as the terrain `tile`.
+    #[must_use]
+    pub fn terrain(mut self, character: char, tile: T) -> Self {
+        self.symbols.insert(character, Symbol::Terrain(tile));
+        self
+    }
+
+    /// Reads `character` as a marker standing on the terrain `tile`.
+    #[must_use]
+    pub fn marker(mut self, character: char, tile: T) -> Self {
+        self.symbols.insert(character, Symbol::Marker(tile));
+        self
+    }
+
+    /// Reads every character not otherwise named as a marker standing on the terrain `tile`.
+    #[must_use]
+    pub fn other_markers(mut self, tile: T) -> Self {
+        self.other_markers = Some(tile);
+        self
+    }
+
+    /// The terrain read for `character`, or `None` if the legend doesn't know it.
+    pub fn tile(&self, character: char) -> Option<T> {
+        self.read(character).map(|(tile, _)| tile)
+    }
+
+    /// The terrain read for `character`, along with whether it is a marker.
+    pub(crate) fn read(&self, character: char) -> Option<(T, bool)> {
+        match self.symbols.get(&character) {
+            Some(Symbol::Terrain(tile)) => Some((tile.clone(), false)),
+            Some(Symbol::Marker(tile)) => Some((tile.clone(), true)),
+            None => self.other_markers.clone().map(|tile| (tile, true)),
+        }
+    }
+}
+
+impl<T: Clone> Default for Legend<T> {
+    fn default() -> Self {
+        Legend::new()
+    }
+}
+
+/// A grid of terrain, along with the positions of the markers which stood on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkedGrid<T> {
+    pub grid: Grid<T>,
+    /// The positions of each marker, in reading order.
+    pub markers: HashMap<char, Vec<Coordinates>>,
+}
+
+impl<T> MarkedGrid<T> {
+    /// The positions of `marker`, empty if it isn't on the grid.
+    pub fn positions(&self, marker: char) -> &[Coordinates] {
+        self.markers.get(&marker).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// A character of a grid which its legend doesn't know.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCharacter(pub char);
+
+impl Display for UnknownCharacter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is not in the legend", self.0)
+    }
+}
+
+impl Error for UnknownCharacter {}
@@ -1 +1,4 @@
 pub mod file_reader;
+pub mod legend;
+pub mod mapped;
+pub mod parse;
@@ -0,0 +1,95 @@
+use memmap2::Mmap;
+use std::fs::File;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+/// A file mapped in memory, whose content is read in place instead of being copied.
+///
+/// Lines are borrowed from the mapping, which makes reading large inputs as cheap as it gets.
+///
+/// # Examples
+/// ```rust
+/// use file_reader::mapped::MappedFile;
+/// # let dir = tempfile::tempdir().unwrap();
+/// # let path = dir.path().join("input");
+/// # std::fs::write(&path, "3   4\r\n4   3\n").unwrap();
+///
+/// // SAFETY: nothing writes to the file while it is mapped.
+/// let file = unsafe { MappedFile::open(&path) }.unwrap();
+///
+/// assert_eq!(vec!["3   4", "4   3"], file.lines().unwrap().collect::<Vec<_>>());
+/// assert_eq!(Some(&b"4   3"[..]), file.byte_lines().nth(1));
+/// ```
+#[derive(Debug)]
+pub struct MappedFile {
+    path: PathBuf,
+    map: Mmap,
+}
+
+impl MappedFile {
+    /// Maps the file at `path` in memory.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the file cannot be opened or mapped.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified, by this process or any other, until the `MappedFile` is
+    /// dropped. The slices borrowed from the mapping would otherwise change under their readers,
+    /// which is undefined behaviour, and truncating the file makes reading past its new end
+    /// crash the process with `SIGBUS`.
+    pub unsafe fn open(path: impl Into<PathBuf>) -> Result<MappedFile, Error> {
+        let path = path.into();
+        let file = File::open(&path)?;
+        // SAFETY: upheld by the caller.
+        let map = unsafe { Mmap::map(&file)? };
+
+        Ok(MappedFile { path, map })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.map
+    }
+
+    /// The whole content of the file.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the file is not valid UTF-8.
+    pub fn text(&self) -> Result<&str, Error> {
+        std::str::from_utf8(&self.map).map_err(|err| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("{} is not valid UTF-8: {err}", self.path.display()),
+            )
+        })
+    }
+
+    /// The lines of the file, without their `\n` or `\r\n`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the file is not valid UTF-8.
+    pub fn lines(&self) -> Result<impl Iterator<Item = &str>, Error> {
+        Ok(self.text()?.lines())
+    }
+
+    /// The lines of the file as bytes, without their `\n` or `\r\n`.
+    pub fn byte_lines(&self) -> impl Iterator<Item = &[u8]> {
+        self.map.split_inclusive(|byte| *byte == b'\n').map(|line| {
+            let line = line.strip_suffix(b"\n").unwrap_or(line);
+            line.strip_suffix(b"\r").unwrap_or(line)
+        })
+    }
+}
+
+impl AsRef<[u8]> for MappedFile {
+    fn as_ref(&self) -> &[u8] {
+        &self.map
+    }
+}
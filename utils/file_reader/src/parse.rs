@@ -0,0 +1,203 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// A line handed to the closure of [`FileReader::parse_lines_with`], without its newline.
+///
+/// Fields parsed through [`Line::parse`] are located in the line, so that an error points to the
+/// column of the offending text.
+///
+/// [`FileReader::parse_lines_with`]: crate::file_reader::FileReader::parse_lines_with
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Line { text }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Parses `field`, a slice of the line such as one of its `split` parts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error located at `field` if it can't be parsed into a `T`.
+    pub fn parse<T: FromStr>(&self, field: &'a str) -> Result<T, FieldError>
+    where
+        T::Err: Display,
+    {
+        field.parse().map_err(|err| self.error(field, err))
+    }
+
+    /// An error about `field`, a slice of the line.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `field` isn't a slice of the line, such as a copy of some of
+    /// its text, whose column can't be known. Release builds locate it at the first column.
+    pub fn error(&self, field: &'a str, message: impl Display) -> FieldError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (field.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + field.len() <= self.text.len());
+        debug_assert!(
+            offset.is_some(),
+            "`{field}` is not a slice of the line `{}`",
+            self.text
+        );
+        let offset = offset.unwrap_or_default();
+
+        FieldError {
+            column: self.text[..offset].chars().count() + 1,
+            text: field.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// An error about a `field` missing from the line, located at its end.
+    pub fn missing(&self, field: &str) -> FieldError {
+        FieldError {
+            column: self.text.chars().count() + 1,
+            text: String::new(),
+            message: format!("missing {field}"),
+        }
+    }
+}
+
+/// An error about some text of a line, before it's known which file and line it comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// The 1-based column, in characters, the offending text starts at.
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl FieldError {
+    /// Locates the error in the line `line` of the file at `path`.
+    pub fn locate(self, path: Option<PathBuf>, line: usize) -> ParseError {
+        ParseError::Invalid {
+            path,
+            line,
+            column: self.column,
+            text: self.text,
+            message: self.message,
+        }
+    }
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "column {}: {}", self.column, self.message)
+    }
+}
+
+impl Error for FieldError {}
+
+/// An error met while parsing the lines of a file.
+#[derive(Debug)]
+pub enum ParseError {
+    /// A line couldn't be read.
+    Read(std::io::Error),
+    /// A line was read but holds invalid text.
+    Invalid {
+        /// The path of the file, or `None` for content read from memory.
+        path: Option<PathBuf>,
+        line: usize,
+        column: usize,
+        text: String,
+        message: String,
+    },
+}
+
+impl Display for ParseError {
+    /// Renders the error as a `file:line:col` diagnostic.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Read(err) => write!(f, "{err}"),
+            ParseError::Invalid {
+                path,
+                line,
+                column,
+                text,
+                message,
+            } => {
+                match path {
+                    Some(path) => write!(f, "{}", path.display())?,
+                    None => write!(f, "<input>")?,
+                }
+                write!(f, ":{line}:{column}: {message}")?;
+
+                if text.is_empty() {
+                    Ok(())
+                } else {
+                    write!(f, " (`{text}`)")
+                }
+            }
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseError::Read(err) => Some(err),
+            ParseError::Invalid { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_fields_in_their_line() {
+        let line = Line::new("3é: 12a 4");
+        let field = line.text().split_whitespace().nth(1).unwrap();
+
+        let err = line.parse::<u32>(field).unwrap_err();
+        assert_eq!(5, err.column);
+        assert_eq!("12a", err.text);
+        assert_eq!("invalid digit found in string", err.message);
+
+        assert_eq!(Ok(4), line.parse::<u32>(&line.text()[9..]));
+        assert_eq!(10, line.missing("a number").column);
+    }
+
+    #[test]
+    fn renders_a_diagnostic() {
+        let line = Line::new("1 x");
+        let err = line
+            .error(&line.text()[2..], "expected a number")
+            .locate(Some(PathBuf::from("day-1/src/input")), 7);
+
+        assert_eq!(
+            "day-1/src/input:7:3: expected a number (`x`)",
+            err.to_string()
+        );
+        assert_eq!(
+            "<input>:2:4: missing a number",
+            Line::new("1 x")
+                .missing("a number")
+                .locate(None, 2)
+                .to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "`12` is not a slice of the line `1 12`")]
+    fn rejects_fields_outside_their_line() {
+        let line = Line::new("1 12");
+        let copy = line.text()[2..].to_string();
+
+        line.error(&copy, "too large");
+    }
+}
@@ -0,0 +1,213 @@
+use num_bigint::{BigInt, BigUint};
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// The answer to one part of a puzzle.
+///
+/// Integers converted or parsed into an answer are stored in the narrowest variant able to hold
+/// them. Answers are compared by value, so two answers holding the same number are equal whatever
+/// variant holds it.
+///
+/// # Examples
+/// ```rust
+/// use num_bigint::BigUint;
+/// use solution::answer::Answer;
+///
+/// assert_eq!(Answer::from(42_i32), Answer::from(42_usize));
+/// assert_eq!(Answer::from(BigUint::from(42_u8)), "42".parse().unwrap());
+/// assert_eq!(Answer::Text("LRUD".to_string()), "LRUD".parse().unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Big(BigInt),
+    Text(String),
+}
+
+impl Answer {
+    /// The number held by the answer, whatever its variant, or `None` for text.
+    fn integer(&self) -> Option<BigInt> {
+        match self {
+            Answer::Unsigned(number) => Some(BigInt::from(*number)),
+            Answer::Signed(number) => Some(BigInt::from(*number)),
+            Answer::Big(number) => Some(number.clone()),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(text), Answer::Text(other_text)) => text == other_text,
+            (Answer::Text(_), _) | (_, Answer::Text(_)) => false,
+            _ => self.integer() == other.integer(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Answer::Text(text) => text.hash(state),
+            _ => self.integer().hash(state),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(number) => write!(f, "{number}"),
+            Answer::Signed(number) => write!(f, "{number}"),
+            Answer::Big(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Parses an answer, reading it as an integer whenever possible and as text otherwise.
+    fn from_str(answer: &str) -> Result<Self, Self::Err> {
+        let answer = answer.trim();
+
+        Ok(match answer.parse::<BigInt>() {
+            Ok(number) => Answer::from(number),
+            Err(_) => Answer::Text(answer.to_string()),
+        })
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(number: BigInt) -> Self {
+        if let Ok(number) = u64::try_from(&number) {
+            Answer::Unsigned(number)
+        } else if let Ok(number) = i64::try_from(&number) {
+            Answer::Signed(number)
+        } else {
+            Answer::Big(number)
+        }
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(number: BigUint) -> Self {
+        Answer::from(BigInt::from(number))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($unsigned:ty),+) => {
+        $(
+            impl From<$unsigned> for Answer {
+                fn from(number: $unsigned) -> Self {
+                    match u64::try_from(number) {
+                        Ok(number) => Answer::Unsigned(number),
+                        Err(_) => Answer::from(BigInt::from(number)),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($signed:ty),+) => {
+        $(
+            impl From<$signed> for Answer {
+                fn from(number: $signed) -> Self {
+                    match (u64::try_from(number), i64::try_from(number)) {
+                        (Ok(number), _) => Answer::Unsigned(number),
+                        (_, Ok(number)) => Answer::Signed(number),
+                        _ => Answer::from(BigInt::from(number)),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_integers_are_normalized() {
+        assert_eq!(Answer::Unsigned(7), Answer::from(7_i8));
+        assert_eq!(Answer::Unsigned(7), Answer::from(BigUint::from(7_u8)));
+        assert_eq!(Answer::Signed(-7), Answer::from(-7_isize));
+        assert_eq!(Answer::Signed(-7), Answer::from(BigInt::from(-7)));
+        assert_eq!(
+            Answer::Big(BigInt::from(u128::MAX)),
+            Answer::from(u128::MAX)
+        );
+        assert_eq!(
+            Answer::Big(BigInt::from(i128::MIN)),
+            Answer::from(i128::MIN)
+        );
+    }
+
+    #[test]
+    fn test_integers_are_compared_by_value() {
+        assert_eq!(Answer::Unsigned(5), Answer::Big(BigInt::from(5)));
+        assert_eq!(Answer::Signed(5), Answer::Unsigned(5));
+        assert_ne!(Answer::Signed(-5), Answer::Unsigned(5));
+        assert_ne!(Answer::Text("5".to_string()), Answer::Unsigned(5));
+
+        let hash = |answer: &Answer| {
+            let mut hasher = std::hash::DefaultHasher::new();
+            answer.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(
+            hash(&Answer::Unsigned(5)),
+            hash(&Answer::Big(BigInt::from(5)))
+        );
+    }
+
+    #[test]
+    fn test_parse_answer() {
+        assert_eq!(Ok(Answer::Unsigned(4924)), "4924".parse());
+        assert_eq!(Ok(Answer::Unsigned(6085)), " 6085\n".parse());
+        assert_eq!(Ok(Answer::Signed(-12)), "-12".parse());
+        assert_eq!(
+            Ok(Answer::Big(BigInt::from(u128::MAX))),
+            u128::MAX.to_string().parse()
+        );
+        assert_eq!(Ok(Answer::Text("8,0,3".to_string())), "8,0,3".parse());
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for answer in [
+            Answer::Unsigned(169_122_112_716_571),
+            Answer::Signed(-3),
+            Answer::from(u128::MAX),
+            Answer::Text("abc".to_string()),
+        ] {
+            assert_eq!(Ok(answer.clone()), answer.to_string().parse());
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Differential testing of solutions against slow but obviously correct reference solutions.
+//!
+//! Both solutions are run on inputs generated with increasing sizes, so that the first
+//! disagreement found is on one of the smallest inputs showing it, which is the easiest one to
+//! debug.
+
+use crate::answer::Answer;
+use crate::generator::{self, TESTED_SEEDS};
+use crate::registry::GenerateFn;
+use crate::{Part, Solution};
+use file_reader::file_reader::FileReader;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Size of the largest inputs generated by [`differential_tests!`] unless another one is given.
+pub const MAX_SIZE: usize = 20;
+
+/// Runs `S` and the reference solution `R` on inputs generated by `generate`, of every size up to
+/// `max_size` and for [`TESTED_SEEDS`] seeds each.
+///
+/// # Errors
+///
+/// Returns a [`Disagreement`] holding the shortest input of the smallest size on which both
+/// solutions give different answers, or the error of a solution which can't parse an input.
+pub fn check<S: Solution, R: Solution>(
+    generate: GenerateFn,
+    max_size: usize,
+) -> Result<(), Box<dyn Error>> {
+    for size in 1..=max_size {
+        let mut disagreements = Vec::new();
+
+        for seed in 0..TESTED_SEEDS {
+            let input = generate(&mut generator::rng(seed), size);
+
+            disagreements.extend(compare::<S, R>(seed, size, input)?);
+        }
+
+        if let Some(disagreement) = disagreements
+            .into_iter()
+            .min_by_key(|disagreement| disagreement.input.len())
+        {
+            return Err(Box::new(disagreement));
+        }
+    }
+
+    Ok(())
+}
+
+/// Solves the input generated from `seed` with `S` and `R`, returning the first part they
+/// disagree on.
+fn compare<S: Solution, R: Solution>(
+    seed: u64,
+    size: usize,
+    input: String,
+) -> Result<Option<Disagreement>, Box<dyn Error>> {
+    let solved = S::solve(FileReader::from_string(input.clone()))?;
+    let reference = R::solve(FileReader::from_string(input.clone()))?;
+    let answers = [
+        (Part::One, solved.0.into(), reference.0.into()),
+        (Part::Two, solved.1.into(), reference.1.into()),
+    ];
+
+    Ok(answers
+        .into_iter()
+        .find(|(_, answer, expected)| answer != expected)
+        .map(|(part, answer, expected)| Disagreement {
+            part,
+            seed,
+            size,
+            input,
+            answer,
+            expected,
+        }))
+}
+
+/// Generates a test checking `$solution` against the reference solution `$reference` on inputs
+/// made by `$generate`, of sizes up to `$max_size` or [`MAX_SIZE`].
+///
+/// ```rust,ignore
+/// #[cfg(test)]
+/// mod test {
+///     use super::*;
+///
+///     solution::differential_tests!(Day6, Day6Reference, generate, max_size = 12);
+/// }
+/// ```
+#[macro_export]
+macro_rules! differential_tests {
+    ($solution:ty, $reference:ty, $generate:path) => {
+        $crate::differential_tests!(
+            $solution,
+            $reference,
+            $generate,
+            max_size = $crate::differential::MAX_SIZE
+        );
+    };
+    ($solution:ty, $reference:ty, $generate:path, max_size = $max_size:expr) => {
+        #[test]
+        fn test_matches_reference() {
+            // Displayed rather than returned, so that the input is printed as is.
+            if let Err(error) =
+                $crate::differential::check::<$solution, $reference>($generate, $max_size)
+            {
+                panic!("{error}");
+            }
+        }
+    };
+}
+
+/// A generated input on which a solution and its reference give different answers.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub part: Part,
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub answer: Answer,
+    pub expected: Answer,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} gave {}, the reference {}, on the input of size {} generated from seed {}:",
+            self.part, self.answer, self.expected, self.size, self.seed
+        )?;
+        write!(f, "{}", self.input)
+    }
+}
+
+impl Error for Disagreement {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generator::GeneratorRng;
+    use rand::Rng;
+
+    /// Sums the numbers of its input, but forgets the last one in part two once there are more
+    /// than three.
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = u32;
+
+        fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+            Ok(reader
+                .map(|line| line.trim().parse())
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part_one(numbers: &Self::Input) -> Self::PartOne {
+            numbers.iter().sum()
+        }
+
+        fn part_two(numbers: &Self::Input) -> Self::PartTwo {
+            match numbers.len() {
+                0..=3 => numbers.iter().sum(),
+                length => numbers[..length - 1].iter().sum(),
+            }
+        }
+    }
+
+    struct ReferenceSum;
+
+    impl Solution for ReferenceSum {
+        type Input = Vec<u32>;
+        type PartOne = u64;
+        type PartTwo = u64;
+
+        fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+            Sum::parse(reader)
+        }
+
+        fn part_one(numbers: &Self::Input) -> Self::PartOne {
+            numbers.iter().copied().map(u64::from).sum()
+        }
+
+        fn part_two(numbers: &Self::Input) -> Self::PartTwo {
+            Self::part_one(numbers)
+        }
+    }
+
+    fn generate(rng: &mut GeneratorRng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{}\n", rng.gen_range(1..100)))
+            .collect()
+    }
+
+    #[test]
+    fn test_agreeing_solutions() {
+        assert!(check::<ReferenceSum, ReferenceSum>(generate, 10).is_ok());
+        assert!(check::<Sum, ReferenceSum>(generate, 3).is_ok());
+    }
+
+    #[test]
+    fn test_smallest_disagreement() {
+        let error = check::<Sum, ReferenceSum>(generate, 10).unwrap_err();
+        let disagreement = error
+            .downcast_ref::<Disagreement>()
+            .expect("The solutions disagree");
+
+        assert_eq!(Part::Two, disagreement.part);
+        assert_eq!(4, disagreement.size);
+        assert_eq!(4, disagreement.input.lines().count());
+        assert_eq!(
+            generate(&mut generator::rng(disagreement.seed), 4),
+            disagreement.input
+        );
+        assert!(error.to_string().starts_with("Part two gave "));
+    }
+}
@@ -0,0 +1,262 @@
+use crate::answer::Answer;
+use crate::registry;
+use crate::{Part, Solution};
+use file_reader::file_reader::FileReader;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The line separating the expected answers of an example from its input.
+pub const SEPARATOR: &str = "---";
+
+/// An example puzzle input along with the answers it is expected to give.
+///
+/// Examples are stored as a header of `part-one: <answer>` and `part-two: <answer>` lines, both
+/// optional as some examples only illustrate one part, followed by a `---` line then the input.
+///
+/// # Examples
+/// ```rust
+/// use solution::answer::Answer;
+/// use solution::example::Example;
+///
+/// let example = Example::parse("part-one: 11\n---\n3 4\n4 3\n").unwrap();
+///
+/// assert_eq!(Some(&Answer::from(11_u8)), example.expected[0].as_ref());
+/// assert_eq!(None, example.expected[1]);
+/// assert_eq!("3 4\n4 3\n", example.input);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The expected answers of part one and part two, when known.
+    pub expected: [Option<Answer>; 2],
+    pub input: String,
+}
+
+impl Example {
+    /// Parses an example from its header and input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header isn't followed by a separator, or has an unknown key.
+    pub fn parse(text: &str) -> Result<Example, MalformedExample> {
+        let mut expected = [None, None];
+        let mut rest = text;
+
+        loop {
+            let (line, next) = rest
+                .split_once('\n')
+                .ok_or(MalformedExample::MissingSeparator)?;
+            rest = next;
+
+            let line = line.trim();
+            if line == SEPARATOR {
+                break;
+            }
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, answer) = line
+                .split_once(':')
+                .ok_or_else(|| MalformedExample::InvalidLine(line.to_string()))?;
+            let part = Part::ALL
+                .into_iter()
+                .find(|part| part.key() == key.trim())
+                .ok_or_else(|| MalformedExample::InvalidLine(line.to_string()))?;
+            let Ok(answer) = answer.parse();
+
+            expected[part.index()] = Some(answer);
+        }
+
+        Ok(Example {
+            expected,
+            input: rest.to_string(),
+        })
+    }
+
+    /// Solves the example with `S`, checking every part that has an expected answer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input can't be parsed, or if a part gives an unexpected answer.
+    pub fn check<S: Solution>(&self) -> Result<(), Box<dyn Error>> {
+        let input = S::parse(FileReader::from_string(self.input.clone()))?;
+
+        self.verify(|part| match part {
+            Part::One => S::part_one(&input).into(),
+            Part::Two => S::part_two(&input).into(),
+        })
+    }
+
+    /// Solves the example with the parser and the default solvers registered for `day`,
+    /// checking every part that has an expected answer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the day has no parser, if the input can't be parsed, or if a part
+    /// gives an unexpected answer.
+    pub fn check_registered(&self, day: u8) -> Result<(), Box<dyn Error>> {
+        let parser = registry::parsers()
+            .into_iter()
+            .find(|parser| parser.day == day)
+            .ok_or(NotRegistered(day))?;
+        let input = (parser.parse)(FileReader::from_string(self.input.clone()))?;
+
+        self.verify(|part| match registry::solvers(day, part).first() {
+            Some(solver) => (solver.solve)(input.as_ref()),
+            None => panic!("{part} of day {day} has no solver"),
+        })
+    }
+
+    /// Checks the answer of every part that has an expected one.
+    fn verify(&self, mut answer: impl FnMut(Part) -> Answer) -> Result<(), Box<dyn Error>> {
+        for part in Part::ALL {
+            let Some(expected) = &self.expected[part.index()] else {
+                continue;
+            };
+            let answer = answer(part);
+
+            if answer != *expected {
+                return Err(Box::new(WrongAnswer {
+                    part,
+                    expected: expected.clone(),
+                    answer,
+                }));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MalformedExample {
+    MissingSeparator,
+    InvalidLine(String),
+}
+
+impl Display for MalformedExample {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MalformedExample::MissingSeparator => {
+                write!(f, "The example has no `{SEPARATOR}` line before its input")
+            }
+            MalformedExample::InvalidLine(line) => write!(
+                f,
+                "Expected `part-one: <answer>` or `part-two: <answer>` in the example header, got `{line}`"
+            ),
+        }
+    }
+}
+
+impl Error for MalformedExample {}
+
+#[derive(Debug)]
+pub struct WrongAnswer {
+    pub part: Part,
+    pub expected: Answer,
+    pub answer: Answer,
+}
+
+impl Display for WrongAnswer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of the example gave {}, expected {}",
+            self.part, self.answer, self.expected
+        )
+    }
+}
+
+impl Error for WrongAnswer {}
+
+#[derive(Debug)]
+pub struct NotRegistered(u8);
+
+impl Display for NotRegistered {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {} has no registered parser", self.0)
+    }
+}
+
+impl Error for NotRegistered {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Count;
+
+    impl Solution for Count {
+        type Input = Vec<String>;
+        type PartOne = usize;
+        type PartTwo = String;
+
+        fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+            Ok(reader.map(|line| line.trim().to_string()).collect())
+        }
+
+        fn part_one(lines: &Self::Input) -> Self::PartOne {
+            lines.len()
+        }
+
+        fn part_two(lines: &Self::Input) -> Self::PartTwo {
+            lines.concat()
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let example = Example::parse("part-two: ab\n\npart-one: 2\n---\na\nb").unwrap();
+
+        assert_eq!(
+            [
+                Some(Answer::from(2_u8)),
+                Some(Answer::Text("ab".to_string()))
+            ],
+            example.expected
+        );
+        assert_eq!("a\nb", example.input);
+    }
+
+    #[test]
+    fn test_malformed_examples() {
+        assert_eq!(
+            Err(MalformedExample::MissingSeparator),
+            Example::parse("part-one: 2\n")
+        );
+        assert_eq!(
+            Err(MalformedExample::InvalidLine("part-three: 2".to_string())),
+            Example::parse("part-three: 2\n---\na")
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let example = Example::parse("part-one: 2\npart-two: ab\n---\na\nb").unwrap();
+        assert!(example.check::<Count>().is_ok());
+
+        let example = Example::parse("part-one: 3\n---\na\nb").unwrap();
+        assert_eq!(
+            "Part one of the example gave 2, expected 3",
+            example.check::<Count>().unwrap_err().to_string()
+        );
+    }
+
+    crate::register!(Count, day = 23);
+
+    #[test]
+    fn test_check_registered() {
+        let example = Example::parse("part-one: 2\npart-two: ab\n---\na\nb").unwrap();
+        assert!(example.check_registered(23).is_ok());
+
+        let example = Example::parse("part-two: ba\n---\na\nb").unwrap();
+        assert_eq!(
+            "Part two of the example gave ab, expected ba",
+            example.check_registered(23).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "Day 22 has no registered parser",
+            example.check_registered(22).unwrap_err().to_string()
+        );
+    }
+}
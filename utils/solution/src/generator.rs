@@ -0,0 +1,67 @@
+//! Random puzzle inputs, for testing solutions beyond the real input and the examples.
+//!
+//! Generators are seeded, so that an input which makes a solution fail can be produced again
+//! from its seed alone. They use a `ChaCha` generator rather than `StdRng`, whose algorithm may
+//! change between versions of `rand`.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// The random number generator handed to the generators.
+pub type GeneratorRng = ChaCha8Rng;
+
+/// Creates the random number generator of a `seed`.
+///
+/// # Examples
+/// ```rust
+/// use rand::Rng;
+/// use solution::generator::rng;
+///
+/// assert_eq!(rng(42).gen_range(0..1000), rng(42).gen_range(0..1000));
+/// ```
+pub fn rng(seed: u64) -> GeneratorRng {
+    GeneratorRng::seed_from_u64(seed)
+}
+
+/// Number of seeds the inputs of a generator are checked with by [`generator_tests!`].
+pub const TESTED_SEEDS: u64 = 20;
+
+/// Generates the tests of the generator of a day: the same seed must always give the same
+/// input, and generated inputs of various sizes must be solved without error.
+///
+/// ```rust,ignore
+/// #[cfg(test)]
+/// mod test {
+///     use super::*;
+///
+///     solution::generator_tests!(Day1, generate);
+/// }
+/// ```
+#[macro_export]
+macro_rules! generator_tests {
+    ($solution:ty, $generate:path) => {
+        #[test]
+        fn test_generator_is_seeded() {
+            let generate = |seed| $generate(&mut $crate::generator::rng(seed), 20);
+
+            assert_eq!(generate(1), generate(1));
+            assert_ne!(generate(1), generate(2));
+        }
+
+        #[test]
+        fn test_generated_inputs_are_solved() -> Result<(), Box<dyn std::error::Error>> {
+            use $crate::Solution as _;
+
+            for seed in 0..$crate::generator::TESTED_SEEDS {
+                for size in [1, 10, 50] {
+                    let input = $generate(&mut $crate::generator::rng(seed), size);
+                    let reader = $crate::file_reader::file_reader::FileReader::from_string(input);
+
+                    <$solution>::solve(reader)?;
+                }
+            }
+
+            Ok(())
+        }
+    };
+}
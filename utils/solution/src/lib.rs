@@ -0,0 +1,125 @@
+pub mod answer;
+pub mod differential;
+pub mod example;
+pub mod generator;
+pub mod registry;
+
+/// Generates one test per `src/examples/<name>.txt` file of a day, checking its answers as an
+/// [`Example`](example::Example).
+pub use aoc_macros::examples;
+
+// Used by the registration macros, so that days don't need to depend on them.
+#[doc(hidden)]
+pub use file_reader;
+#[doc(hidden)]
+pub use inventory;
+
+// Lets the tests use the attributes of `aoc_macros`, which name this crate by its path.
+#[cfg(test)]
+extern crate self as solution;
+
+use crate::answer::Answer;
+use file_reader::file_reader::FileReader;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// A solver for one day of the calendar, split into its parsing and solving stages.
+///
+/// Implementors only describe how to parse the puzzle input and how to answer each part, so any
+/// tool (runner, benchmarks, tests) can drive every day the same way.
+///
+/// # Examples
+/// ```rust
+/// use file_reader::file_reader::FileReader;
+/// use solution::Solution;
+/// use std::error::Error;
+///
+/// struct Sum;
+///
+/// impl Solution for Sum {
+///     type Input = Vec<usize>;
+///     type PartOne = usize;
+///     type PartTwo = usize;
+///
+///     fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+///         Ok(reader.map(|line| line.trim().parse()).collect::<Result<_, _>>()?)
+///     }
+///
+///     fn part_one(input: &Self::Input) -> Self::PartOne {
+///         input.iter().sum()
+///     }
+///
+///     fn part_two(input: &Self::Input) -> Self::PartTwo {
+///         input.iter().product()
+///     }
+/// }
+/// ```
+pub trait Solution {
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    /// Parses the puzzle input read by `reader`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input cannot be read or is not a valid puzzle input.
+    fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>>;
+
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+
+    /// Parses the puzzle input then solves both parts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input cannot be parsed.
+    fn solve(reader: FileReader) -> Result<(Self::PartOne, Self::PartTwo), Box<dyn Error>> {
+        let input = Self::parse(reader)?;
+
+        Ok((Self::part_one(&input), Self::part_two(&input)))
+    }
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// The number of this part in the puzzle, `1` or `2`.
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    /// The position of this part in per-part arrays, `0` for part one and `1` for part two.
+    pub fn index(self) -> usize {
+        usize::from(self.number() - 1)
+    }
+
+    /// The key naming this part in configuration files, e.g. `part-one`.
+    pub fn key(self) -> &'static str {
+        match self {
+            Part::One => "part-one",
+            Part::Two => "part-two",
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "Part one"),
+            Part::Two => write!(f, "Part two"),
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! Solutions registered with the runner, collected from every linked crate at startup.
+//!
+//! Days register a parser and one or more solvers per part, either through the `#[aoc_parse]`
+//! and `#[aoc]` attributes of `aoc_macros` or, for a type implementing [`Solution`], through
+//! [`register!`](crate::register). A part may have several solvers told apart by their name,
+//! the one named [`DEFAULT_NAME`] being run unless another one is selected.
+//!
+//! Days may also register a [`Generator`] of random inputs, through `#[aoc_generator]`.
+//!
+//! Parsers and solvers of a day only meet at runtime, so the input produced by the parser is
+//! passed around as [`Any`] and each solver downcasts it back to the type it expects.
+//!
+//! [`Solution`]: crate::Solution
+
+use crate::Part;
+use crate::answer::Answer;
+use crate::generator::GeneratorRng;
+use file_reader::file_reader::FileReader;
+use std::any::Any;
+use std::error::Error;
+
+/// Name of the solver run when none is selected.
+pub const DEFAULT_NAME: &str = "default";
+
+pub type ParseFn = fn(FileReader) -> Result<Box<dyn Any>, Box<dyn Error>>;
+pub type SolveFn = fn(&dyn Any) -> Answer;
+pub type GenerateFn = fn(&mut GeneratorRng, usize) -> String;
+
+/// Parses the input of a day.
+#[derive(Debug)]
+pub struct Parser {
+    pub day: u8,
+    /// Path to the puzzle input shipped with the crate of the day.
+    pub input: &'static str,
+    pub parse: ParseFn,
+}
+
+/// Solves one part of a day from the parsed input.
+#[derive(Debug)]
+pub struct Solver {
+    pub day: u8,
+    pub part: Part,
+    pub name: &'static str,
+    pub solve: SolveFn,
+}
+
+/// Generates random inputs of a day, of a size whose meaning depends on the day.
+#[derive(Debug)]
+pub struct Generator {
+    pub day: u8,
+    pub generate: GenerateFn,
+}
+
+inventory::collect!(Parser);
+inventory::collect!(Solver);
+inventory::collect!(Generator);
+
+/// Every registered parser, sorted by day.
+pub fn parsers() -> Vec<&'static Parser> {
+    let mut parsers = inventory::iter::<Parser>.into_iter().collect::<Vec<_>>();
+    parsers.sort_by_key(|parser| parser.day);
+
+    parsers
+}
+
+/// The solvers of one part of a day, the default one first then by name.
+pub fn solvers(day: u8, part: Part) -> Vec<&'static Solver> {
+    let mut solvers = inventory::iter::<Solver>
+        .into_iter()
+        .filter(|solver| solver.day == day && solver.part == part)
+        .collect::<Vec<_>>();
+    solvers.sort_by_key(|solver| (solver.name != DEFAULT_NAME, solver.name));
+
+    solvers
+}
+
+/// The generator of random inputs of a day, if it has one.
+pub fn generator(day: u8) -> Option<&'static Generator> {
+    inventory::iter::<Generator>
+        .into_iter()
+        .find(|generator| generator.day == day)
+}
+
+/// Downcasts the parsed input handed to a solver.
+///
+/// # Panics
+///
+/// Panics if the parser of the day produced another type than the one the solver expects.
+pub fn downcast_input<'a, T: Any>(input: &'a dyn Any, day: u8, part: Part, name: &str) -> &'a T {
+    input.downcast_ref::<T>().unwrap_or_else(|| {
+        panic!(
+            "{part} of day {day} ({name}) expects a `{}`, which its parser doesn't produce",
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+/// Path to the puzzle input shipped with the crate of the day expanding it.
+#[macro_export]
+macro_rules! input_path {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/src/input")
+    };
+}
+
+/// Registers a type implementing [`Solution`](crate::Solution) as the parser and the default
+/// solvers of a day.
+///
+/// ```rust,ignore
+/// solution::register!(Day8, day = 8);
+/// ```
+#[macro_export]
+macro_rules! register {
+    ($solution:ty, day = $day:literal) => {
+        const _: () = {
+            use $crate::Solution as _;
+
+            fn parse(
+                reader: $crate::file_reader::file_reader::FileReader,
+            ) -> Result<Box<dyn std::any::Any>, Box<dyn std::error::Error>> {
+                Ok(Box::new(<$solution>::parse(reader)?))
+            }
+
+            fn part_one(input: &dyn std::any::Any) -> $crate::answer::Answer {
+                let input = $crate::registry::downcast_input(
+                    input,
+                    $day,
+                    $crate::Part::One,
+                    $crate::registry::DEFAULT_NAME,
+                );
+                <$solution>::part_one(input).into()
+            }
+
+            fn part_two(input: &dyn std::any::Any) -> $crate::answer::Answer {
+                let input = $crate::registry::downcast_input(
+                    input,
+                    $day,
+                    $crate::Part::Two,
+                    $crate::registry::DEFAULT_NAME,
+                );
+                <$solution>::part_two(input).into()
+            }
+
+            $crate::inventory::submit! {
+                $crate::registry::Parser {
+                    day: $day,
+                    input: $crate::input_path!(),
+                    parse,
+                }
+            }
+            $crate::inventory::submit! {
+                $crate::registry::Solver {
+                    day: $day,
+                    part: $crate::Part::One,
+                    name: $crate::registry::DEFAULT_NAME,
+                    solve: part_one,
+                }
+            }
+            $crate::inventory::submit! {
+                $crate::registry::Solver {
+                    day: $day,
+                    part: $crate::Part::Two,
+                    name: $crate::registry::DEFAULT_NAME,
+                    solve: part_two,
+                }
+            }
+        };
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Solution;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        type Input = Vec<usize>;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(reader: FileReader) -> Result<Self::Input, Box<dyn Error>> {
+            Ok(reader.map(|line| line.trim().len()).collect())
+        }
+
+        fn part_one(lengths: &Self::Input) -> Self::PartOne {
+            lengths.iter().sum()
+        }
+
+        fn part_two(lengths: &Self::Input) -> Self::PartTwo {
+            lengths.iter().copied().max().unwrap_or_default()
+        }
+    }
+
+    crate::register!(Lengths, day = 25);
+
+    fn longest_first(input: &dyn Any) -> Answer {
+        downcast_input::<Vec<usize>>(input, 25, Part::Two, "first")
+            .first()
+            .copied()
+            .unwrap_or_default()
+            .into()
+    }
+
+    inventory::submit! {
+        Solver { day: 25, part: Part::Two, name: "first", solve: longest_first }
+    }
+
+    // Named like the function registering it, which must not shadow it.
+    #[aoc_macros::aoc_parse(day = 24)]
+    fn parse(reader: FileReader) -> Result<Vec<String>, Box<dyn Error>> {
+        Ok(reader.map(|line| line.trim().to_string()).collect())
+    }
+
+    #[aoc_macros::aoc(day = 24, part = 1)]
+    fn solve(words: &[String]) -> usize {
+        words.len()
+    }
+
+    #[aoc_macros::aoc(day = 24, part = 2, name = "joined")]
+    fn join_words(words: &[String]) -> String {
+        words.concat()
+    }
+
+    #[test]
+    fn test_attributes() -> Result<(), Box<dyn Error>> {
+        let parser = parsers()
+            .into_iter()
+            .find(|parser| parser.day == 24)
+            .expect("Day 24 is registered");
+        let input = (parser.parse)(FileReader::from_string("ab\ncd\n".to_string()))?;
+
+        let [part_one, part_two] = Part::ALL.map(|part| solvers(24, part));
+        assert_eq!(DEFAULT_NAME, part_one[0].name);
+        assert_eq!(Answer::from(2_u8), (part_one[0].solve)(input.as_ref()));
+        assert_eq!("joined", part_two[0].name);
+        assert_eq!(
+            Answer::Text("abcd".to_string()),
+            (part_two[0].solve)(input.as_ref())
+        );
+        assert!(parser.input.ends_with("/src/input"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_registered_solution() -> Result<(), Box<dyn Error>> {
+        let parser = parsers()
+            .into_iter()
+            .find(|parser| parser.day == 25)
+            .expect("Day 25 is registered");
+        let input = (parser.parse)(FileReader::from_string("abc\nabcde\n".to_string()))?;
+
+        let part_one = solvers(25, Part::One);
+        assert_eq!(1, part_one.len());
+        assert_eq!(Answer::from(8_u8), (part_one[0].solve)(input.as_ref()));
+
+        let part_two = solvers(25, Part::Two);
+        assert_eq!(
+            vec![DEFAULT_NAME, "first"],
+            part_two
+                .iter()
+                .map(|solver| solver.name)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Answer::from(3_u8), (part_two[1].solve)(input.as_ref()));
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "Part two of day 25 (first) expects a `alloc::string::String`")]
+    fn test_mismatched_input_panics() {
+        downcast_input::<String>(&vec![1_usize], 25, Part::Two, "first");
+    }
+}